@@ -0,0 +1,329 @@
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use grid::Grid;
+
+/// A simulation that can be replayed frame by frame.
+pub trait Animation {
+    /// Advances the simulation by one step, returns `false` once it is finished.
+    fn step(&mut self) -> bool;
+
+    fn frame(&self) -> Grid<Styled>;
+
+    /// Status line shown below the frame.
+    fn caption(&self) -> String {
+        String::new()
+    }
+}
+
+/// ANSI foreground colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red = 31,
+    Green = 32,
+    Yellow = 33,
+    Blue = 34,
+    Magenta = 35,
+    Cyan = 36,
+    White = 37,
+    Gray = 90,
+}
+
+/// A single character of a frame with an optional color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Styled {
+    pub symbol: char,
+    pub color: Option<Color>,
+}
+
+impl Styled {
+    pub fn plain(symbol: char) -> Self {
+        Self {
+            symbol,
+            color: None,
+        }
+    }
+
+    pub fn colored(symbol: char, color: Color) -> Self {
+        Self {
+            symbol,
+            color: Some(color),
+        }
+    }
+}
+
+/// Renders a frame with ANSI color codes, only switching colors where they change.
+pub fn render(frame: &Grid<Styled>) -> String {
+    let mut output = String::new();
+
+    for row in frame.rows() {
+        let mut current = None;
+        for cell in row {
+            if cell.color != current {
+                match cell.color {
+                    Some(color) => write!(output, "\x1b[{}m", color as u8).unwrap(),
+                    None => output.push_str("\x1b[0m"),
+                }
+                current = cell.color;
+            }
+            output.push(cell.symbol);
+        }
+        if current.is_some() {
+            output.push_str("\x1b[0m");
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    /// Advance a single step, pausing the animation
+    Step,
+    Quit,
+}
+
+impl Control {
+    /// Reads a control from a line of input: empty steps, `p` pauses and resumes, `q` quits.
+    pub fn parse(line: &str) -> Option<Control> {
+        match line.trim() {
+            "" => Some(Control::Step),
+            "p" => Some(Control::TogglePause),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Controls typed on stdin, one per line, read on a background thread.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(control) = Control::parse(&line) {
+                if sender.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Plays an [`Animation`] on a terminal, redrawing the screen for every step.
+#[derive(Debug, Clone)]
+pub struct Player {
+    delay: Duration,
+    paused: bool,
+    clear: bool,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(100),
+            paused: false,
+            clear: true,
+        }
+    }
+}
+
+impl Player {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Time between two steps while playing.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Starts paused, waiting for single steps.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Whether to clear the screen before each frame instead of printing them one below the
+    /// other.
+    pub fn clear(mut self, clear: bool) -> Self {
+        self.clear = clear;
+        self
+    }
+
+    /// Plays until the animation finishes or [`Control::Quit`] comes in, returning the
+    /// number of steps taken.
+    pub fn play<A, W>(
+        &self,
+        animation: &mut A,
+        controls: &Receiver<Control>,
+        out: &mut W,
+    ) -> io::Result<usize>
+    where
+        A: Animation + ?Sized,
+        W: Write,
+    {
+        let mut paused = self.paused;
+        let mut steps = 0;
+        // Without anyone sending controls, keep playing at the configured pace
+        let mut disconnected = false;
+
+        self.draw(animation, paused, out)?;
+        loop {
+            let control = if disconnected {
+                thread::sleep(self.delay);
+                None
+            } else if paused {
+                match controls.recv() {
+                    Ok(control) => Some(control),
+                    Err(_) => {
+                        disconnected = true;
+                        paused = false;
+                        continue;
+                    }
+                }
+            } else {
+                match controls.recv_timeout(self.delay) {
+                    Ok(control) => Some(control),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        disconnected = true;
+                        None
+                    }
+                }
+            };
+
+            match control {
+                Some(Control::Quit) => return Ok(steps),
+                Some(Control::TogglePause) => {
+                    paused = !paused;
+                    self.draw(animation, paused, out)?;
+                    continue;
+                }
+                Some(Control::Step) => paused = true,
+                None => {}
+            }
+
+            let running = animation.step();
+            steps += 1;
+            self.draw(animation, paused, out)?;
+            if !running {
+                return Ok(steps);
+            }
+        }
+    }
+
+    fn draw<A, W>(&self, animation: &A, paused: bool, out: &mut W) -> io::Result<()>
+    where
+        A: Animation + ?Sized,
+        W: Write,
+    {
+        if self.clear {
+            // Cursor home and clear screen
+            write!(out, "\x1b[H\x1b[2J")?;
+        }
+        write!(out, "{}", render(&animation.frame()))?;
+
+        let status = if paused {
+            "paused: Enter steps, p resumes, q quits"
+        } else {
+            "p pauses, q quits"
+        };
+        writeln!(out, "{}\n{}", animation.caption(), status)?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, time::Duration};
+
+    use grid::Grid;
+
+    use crate::{render, Animation, Color, Control, Player, Styled};
+
+    /// Lights one more cell per step
+    struct Counter {
+        lit: usize,
+    }
+
+    impl Animation for Counter {
+        fn step(&mut self) -> bool {
+            self.lit += 1;
+            self.lit < 3
+        }
+
+        fn frame(&self) -> Grid<Styled> {
+            let mut frame = Grid::new(3, 1, Styled::plain('.'));
+            for x in 0..self.lit {
+                frame[(x, 0)] = Styled::colored('#', Color::Green);
+            }
+            frame
+        }
+
+        fn caption(&self) -> String {
+            format!("{} lit", self.lit)
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let mut frame = Grid::new(3, 2, Styled::plain('.'));
+        frame[(0, 0)] = Styled::colored('#', Color::Green);
+        frame[(1, 0)] = Styled::colored('#', Color::Green);
+        frame[(2, 1)] = Styled::colored('*', Color::Yellow);
+
+        assert_eq!(render(&frame), "\x1b[32m##\x1b[0m.\n..\x1b[33m*\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_play_until_finished() {
+        let (_sender, controls) = mpsc::channel();
+        let player = Player::new().delay(Duration::ZERO).clear(false);
+        let mut output = vec![];
+
+        let steps = player
+            .play(&mut Counter { lit: 0 }, &controls, &mut output)
+            .unwrap();
+        assert_eq!(steps, 3);
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches(" lit\n").count(), 4);
+        assert!(output.contains("3 lit"));
+    }
+
+    #[test]
+    fn test_single_step_and_quit() {
+        let (sender, controls) = mpsc::channel();
+        for control in [Control::Step, Control::Step, Control::Quit] {
+            sender.send(control).unwrap();
+        }
+        let player = Player::new().paused(true).clear(false);
+        let mut output = vec![];
+
+        let steps = player
+            .play(&mut Counter { lit: 0 }, &controls, &mut output)
+            .unwrap();
+        assert_eq!(steps, 2);
+        assert!(String::from_utf8(output).unwrap().contains("paused"));
+    }
+
+    #[test]
+    fn test_control_parse() {
+        assert_eq!(Control::parse(""), Some(Control::Step));
+        assert_eq!(Control::parse("p\n"), Some(Control::TogglePause));
+        assert_eq!(Control::parse("q"), Some(Control::Quit));
+        assert_eq!(Control::parse("x"), None);
+    }
+}
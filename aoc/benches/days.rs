@@ -0,0 +1,73 @@
+//! Parsing and both parts of every day, benchmarked separately on the example inputs.
+//!
+//! Record a baseline before a change and compare against it afterwards:
+//!
+//! ```text
+//! cargo bench -p aoc -- --save-baseline main
+//! cargo bench -p aoc -- --baseline main
+//! ```
+//!
+//! Baselines are kept in `target/criterion`, a filter such as `day_15` narrows the run down.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use common::Solution;
+
+macro_rules! example {
+    ($day:literal) => {
+        include_str!(concat!("../../day_", $day, "/src/test.input.txt"))
+    };
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, input: &str) {
+    let mut group = c.benchmark_group(format!("day_{:02}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+
+    let parsed = S::parse(input).expect("example input should parse");
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c, example!("01"));
+    bench_day::<day_02::Day02>(c, example!("02"));
+    bench_day::<day_03::Day03>(c, example!("03"));
+    bench_day::<day_04::Day04>(c, example!("04"));
+    bench_day::<day_05::Day05>(c, example!("05"));
+    bench_day::<day_06::Day06>(c, example!("06"));
+    bench_day::<day_07::Day07>(c, example!("07"));
+    bench_day::<day_08::Day08>(c, example!("08"));
+    bench_day::<day_09::Day09>(c, example!("09"));
+    bench_day::<day_10::Day10>(c, example!("10"));
+    bench_day::<day_11::Day11>(c, example!("11"));
+    bench_day::<day_12::Day12>(c, example!("12"));
+    bench_day::<day_13::Day13>(c, example!("13"));
+    bench_day::<day_14::Day14>(c, example!("14"));
+    bench_day::<day_15::Day15>(c, example!("15"));
+    bench_day::<day_16::Day16>(c, example!("16"));
+    bench_day::<day_17::Day17>(c, example!("17"));
+    bench_day::<day_18::Day18>(c, include_str!("../../day_18/src/input.test.txt"));
+    bench_day::<day_19::Day19>(c, example!("19"));
+    bench_day::<day_20::Day20>(c, example!("20"));
+    bench_day::<day_22::Day22>(c, example!("22"));
+    bench_day::<day_24::Day24>(c, example!("24"));
+    bench_day::<day_25::Day25>(c, example!("25"));
+}
+
+// The quantum game and the amphipod search explore millions of states per iteration, so they
+// get fewer samples
+fn slow_days(c: &mut Criterion) {
+    bench_day::<day_21::Day21>(c, example!("21"));
+    bench_day::<day_23::Day23>(c, example!("23"));
+}
+
+criterion_group!(benches, days);
+criterion_group! {
+    name = slow_benches;
+    config = Criterion::default().sample_size(10);
+    targets = slow_days
+}
+criterion_main!(benches, slow_benches);
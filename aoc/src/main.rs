@@ -0,0 +1,607 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use output::{Format, Record, Report};
+
+mod output;
+mod scaffold;
+
+use animation::{Animation, Player};
+use common::{
+    answers::{Answers, Verdict},
+    input::Source,
+    parse::ParseError,
+    Answer, Day, Part, Solution, SolveError,
+};
+use day_02::{
+    simulator::{simulate, Export, Model},
+    Day02,
+};
+
+const USAGE: &str =
+    "Usage: aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <table|json|jsonl>]
+       aoc verify <day|all>
+       aoc animate <11|13|20> [--input <path>] [--delay <ms>] [--paused]
+       aoc trajectory 2 [--part <1|2>] [--input <path>] [--format <csv|svg>]
+       aoc new <day>
+
+Inputs are read from --input, $AOC_INPUT_DIR/day_NN.txt or day_NN/src/input.txt.
+json prints a single array and jsonl one object per line, each holding day, part, answer,
+elapsed_ms and input_sha256.
+verify checks every day_NN/src/*.txt against the answers in answers.txt.
+While animating, Enter steps, p pauses or resumes and q quits.
+trajectory prints every state of the day 2 submarine, part 2 and csv unless told otherwise.
+new creates the day_NN crate from aoc/templates and adds it to the workspace.
+Set $AOC_TRACE to a filter like debug or '[{day=15}]=debug' (a single day) to trace parsing,
+both parts and the counters of each day on stderr, and $AOC_TRACE_FORMAT to compact, pretty
+or json.";
+
+/// Days with a simulation that `aoc animate` can replay
+const ANIMATED: [u8; 3] = [11, 13, 20];
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+fn main() {
+    common::trace::init();
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input.as_deref(), format),
+        Command::Verify { day } => verify(day),
+        Command::Animate {
+            day,
+            input,
+            delay,
+            paused,
+        } => animate(day, input.as_deref(), delay, paused),
+        Command::Trajectory {
+            part,
+            input,
+            format,
+        } => trajectory(part, input.as_deref(), format),
+        Command::New { day } => new(day),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    // `None` runs every day / both parts
+    Run {
+        day: Option<u8>,
+        part: Option<Part>,
+        input: Option<String>,
+        format: Format,
+    },
+    Verify {
+        day: Option<u8>,
+    },
+    Animate {
+        day: u8,
+        input: Option<String>,
+        delay: Duration,
+        paused: bool,
+    },
+    // Only day 2 has one
+    Trajectory {
+        part: Part,
+        input: Option<String>,
+        format: Export,
+    },
+    New {
+        day: u8,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+
+    let command = match args.next() {
+        Some("new") => return parse_new(args.collect()),
+        Some(command @ ("run" | "verify" | "animate" | "trajectory")) => command,
+        Some(other) => return Err(format!("Unknown command '{}'", other)),
+        None => return Err("Missing command".to_string()),
+    };
+
+    let day = match args.next() {
+        Some("all") => None,
+        Some(day) => Some(parse_day(day)?),
+        None => return Err("Missing day".to_string()),
+    };
+
+    let mut part = None;
+    let mut input = None;
+    let mut delay = DEFAULT_DELAY;
+    let mut paused = false;
+    let mut format = Format::Table;
+    let mut export = Export::Csv;
+    while let Some(arg) = args.next() {
+        match (command, arg) {
+            ("run" | "trajectory", "--part" | "-p") => part = Some(parse_part(args.next())?),
+            ("run" | "animate" | "trajectory", "--input" | "-i") => match args.next() {
+                Some(path) => input = Some(path.to_string()),
+                None => return Err("Missing path after --input".to_string()),
+            },
+            ("run", "--format" | "-f") => format = parse_format(args.next())?,
+            ("trajectory", "--format" | "-f") => export = parse_export(args.next())?,
+            ("animate", "--delay") => delay = parse_delay(args.next())?,
+            ("animate", "--paused") => paused = true,
+            (_, other) => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+
+    if day.is_none() && input.is_some() {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    match command {
+        "run" => Ok(Command::Run {
+            day,
+            part,
+            input,
+            format,
+        }),
+        "verify" => Ok(Command::Verify { day }),
+        "trajectory" => match day {
+            Some(2) => Ok(Command::Trajectory {
+                part: part.unwrap_or(Part::Two),
+                input,
+                format: export,
+            }),
+            _ => Err("Only day 2 has a trajectory".to_string()),
+        },
+        _ => match day {
+            Some(day) if ANIMATED.contains(&day) => Ok(Command::Animate {
+                day,
+                input,
+                delay,
+                paused,
+            }),
+            _ => Err("Only days 11, 13 and 20 can be animated".to_string()),
+        },
+    }
+}
+
+fn parse_new(args: Vec<&str>) -> Result<Command, String> {
+    match args[..] {
+        [raw] => match raw.parse() {
+            Ok(day @ 1..=25) => Ok(Command::New { day }),
+            _ => Err(format!("Invalid day '{}'", raw)),
+        },
+        [] => Err("Missing day".to_string()),
+        [_, other, ..] => Err(format!("Unknown argument '{}'", other)),
+    }
+}
+
+fn parse_day(raw: &str) -> Result<u8, String> {
+    raw.parse::<u8>()
+        .ok()
+        .filter(|day| days().iter().any(|d| d.number == *day))
+        .ok_or_else(|| format!("No solution for day '{}'", raw))
+}
+
+fn parse_part(raw: Option<&str>) -> Result<Part, String> {
+    match raw {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(other) => Err(format!("Invalid part '{}'", other)),
+        None => Err("Missing part after --part".to_string()),
+    }
+}
+
+fn parse_format(raw: Option<&str>) -> Result<Format, String> {
+    match raw {
+        Some(raw) => Format::parse(raw).ok_or_else(|| format!("Invalid format '{}'", raw)),
+        None => Err("Missing format after --format".to_string()),
+    }
+}
+
+fn parse_export(raw: Option<&str>) -> Result<Export, String> {
+    match raw {
+        Some(raw) => Export::parse(raw).ok_or_else(|| format!("Invalid format '{}'", raw)),
+        None => Err("Missing format after --format".to_string()),
+    }
+}
+
+fn parse_delay(raw: Option<&str>) -> Result<Duration, String> {
+    match raw {
+        Some(raw) => raw
+            .parse()
+            .map(Duration::from_millis)
+            .map_err(|_| format!("Invalid delay '{}'", raw)),
+        None => Err("Missing milliseconds after --delay".to_string()),
+    }
+}
+
+fn days() -> Vec<Day> {
+    common::days!()
+}
+
+fn manifest_dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{:02}", day))
+}
+
+fn run(day: Option<u8>, part: Option<Part>, input: Option<&str>, format: Format) {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut report = Report::new(format);
+
+    let mut failed = false;
+    for d in days()
+        .iter()
+        .filter(|d| day.is_none_or(|day| day == d.number))
+    {
+        let source = Source::from_env(input, d.number, &manifest_dir(d.number));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}", error);
+                failed = true;
+                continue;
+            }
+        };
+
+        let input_sha256 = output::sha256(&input);
+        for part in &parts {
+            let start = Instant::now();
+            match d.run(&input, *part) {
+                Ok(answer) => report.push(Record::new(
+                    d.number,
+                    *part,
+                    answer,
+                    start.elapsed(),
+                    &input_sha256,
+                )),
+                Err(SolveError::Parse(error)) => {
+                    eprintln!("Malformed input for day {}: {}", d.number, error);
+                    failed = true;
+                    // Both parts share the parser, no need to report it twice
+                    break;
+                }
+                Err(error) => {
+                    eprintln!("Day {} part {}: {}", d.number, part, error);
+                    failed = true;
+                }
+            }
+        }
+    }
+    report.finish();
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn verify(day: Option<u8>) {
+    let answers = load_answers();
+
+    println!(
+        "{:>3} | {:>4} | {:<20} | {:<7} | Answer",
+        "Day", "Part", "Input", "Result"
+    );
+    println!("{:-<4}+{:-<6}+{:-<22}+{:-<9}+{:-<21}", "", "", "", "", "");
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for d in days()
+        .iter()
+        .filter(|d| day.is_none_or(|day| day == d.number))
+    {
+        for (name, path) in inputs(d.number) {
+            let input = match Source::File(path).read() {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for part in [Part::One, Part::Two] {
+                let answer = match d.run(&input, part) {
+                    Ok(answer) => answer,
+                    Err(SolveError::Parse(error)) => {
+                        eprintln!("Malformed input {} for day {}: {}", name, d.number, error);
+                        failed += 1;
+                        break;
+                    }
+                    Err(error) => {
+                        eprintln!(
+                            "Input {} for day {} part {}: {}",
+                            name, d.number, part, error
+                        );
+                        failed += 1;
+                        continue;
+                    }
+                };
+
+                let verdict = answers.check(d.number, part, &name, &answer);
+                match verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::Fail { .. } => failed += 1,
+                    Verdict::Unknown => unknown += 1,
+                }
+                print_verdict(d.number, part, &name, &answer, &verdict);
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} unknown",
+        passed, failed, unknown
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn load_answers() -> Answers {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.txt");
+    let raw = Source::File(path.clone()).read().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    Answers::parse(&raw).unwrap_or_else(|error| {
+        eprintln!("Malformed {}: {}", path.display(), error);
+        process::exit(1);
+    })
+}
+
+/// Every `*.txt` input kept next to a day's solution, named by its file stem.
+fn inputs(day: u8) -> Vec<(String, PathBuf)> {
+    let entries = match fs::read_dir(manifest_dir(day).join("src")) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut inputs = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            Some((name, path))
+        })
+        .collect::<Vec<_>>();
+    inputs.sort();
+    inputs
+}
+
+fn new(day: u8) {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    match scaffold::create(&root, day) {
+        Ok(files) => {
+            for file in files {
+                let file = file.strip_prefix(&root).unwrap_or(&file);
+                println!("Created {}", file.display());
+            }
+            println!(
+                "Paste the example into day_{:02}/src/test.input.txt and your input into \
+                 day_{:02}/src/input.txt, then add Day{:02} to common::days! in \
+                 common/src/lib.rs and day_{:02} to the aoc, playground and fuzz dependencies",
+                day, day, day, day
+            );
+        }
+        Err(error) => {
+            eprintln!("Could not create day {}: {}", day, error);
+            process::exit(1);
+        }
+    }
+}
+
+fn print_verdict(day: u8, part: Part, input: &str, answer: &Answer, verdict: &Verdict) {
+    let result = match verdict {
+        Verdict::Pass => "pass",
+        Verdict::Fail { .. } => "FAIL",
+        Verdict::Unknown => "unknown",
+    };
+    let answer = answer.to_string();
+
+    match verdict {
+        Verdict::Fail { expected } if !answer.contains('\n') && !expected.contains('\n') => {
+            println!(
+                "{:>3} | {:>4} | {:<20} | {:<7} | {} (expected {})",
+                day, part, input, result, answer, expected
+            );
+        }
+        Verdict::Fail { expected } => {
+            println!("{:>3} | {:>4} | {:<20} | {:<7} |", day, part, input, result);
+            print_indented("found", &answer);
+            print_indented("expected", expected);
+        }
+        _ if answer.contains('\n') => {
+            println!("{:>3} | {:>4} | {:<20} | {:<7} |", day, part, input, result);
+            print_indented("", &answer);
+        }
+        _ => println!(
+            "{:>3} | {:>4} | {:<20} | {:<7} | {}",
+            day, part, input, result, answer
+        ),
+    }
+}
+
+fn print_indented(label: &str, text: &str) {
+    if !label.is_empty() {
+        println!("      {}:", label);
+    }
+    for line in text.lines() {
+        println!("      {}", line);
+    }
+}
+
+fn animate(day: u8, input: Option<&str>, delay: Duration, paused: bool) {
+    let source = Source::from_env(input, day, &manifest_dir(day));
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let mut animation = load_animation(day, &input).unwrap_or_else(|error| {
+        eprintln!("Malformed input for day {}: {}", day, error);
+        process::exit(1);
+    });
+
+    let controls = animation::stdin_controls();
+    let player = Player::new().delay(delay).paused(paused);
+    if let Err(error) = player.play(animation.as_mut(), &controls, &mut io::stdout().lock()) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn trajectory(part: Part, input: Option<&str>, format: Export) {
+    let day = Day02::DAY;
+    let source = Source::from_env(input, day, &manifest_dir(day));
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let script = Day02::parse(&input).unwrap_or_else(|error| {
+        eprintln!("Malformed input for day {}: {}", day, error);
+        process::exit(1);
+    });
+    let model = match part {
+        Part::One => Model::Direct,
+        Part::Two => Model::Aimed,
+    };
+    let trajectory = simulate(&script, model).unwrap_or_else(|error| {
+        eprintln!("Day {} part {}: {}", day, part, error);
+        process::exit(1);
+    });
+
+    if let Err(error) = trajectory.export(format, &mut io::stdout().lock()) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn load_animation(day: u8, input: &str) -> Result<Box<dyn Animation>, ParseError> {
+    Ok(match day {
+        11 => Box::new(day_11::animation(&day_11::Day11::parse(input)?)),
+        13 => Box::new(day_13::animation(&day_13::Day13::parse(input)?)),
+        20 => Box::new(day_20::animation(&day_20::Day20::parse(input)?, 50)),
+        _ => unreachable!("day {} has no animation", day),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::Part;
+
+    use day_02::simulator::Export;
+
+    use crate::{output::Format, parse_args, Command};
+
+    fn args(raw: &str) -> Vec<String> {
+        raw.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args("run 15 --part 2")),
+            Ok(Command::Run {
+                day: Some(15),
+                part: Some(Part::Two),
+                input: None,
+                format: Format::Table
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run all")),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None,
+                format: Format::Table
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 1 --input -")),
+            Ok(Command::Run {
+                day: Some(1),
+                part: None,
+                input: Some("-".to_string()),
+                format: Format::Table
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run all --format jsonl")),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None,
+                format: Format::JsonLines
+            })
+        );
+        assert_eq!(
+            parse_args(&args("verify all")),
+            Ok(Command::Verify { day: None })
+        );
+        assert_eq!(parse_args(&args("new 5")), Ok(Command::New { day: 5 }));
+        assert_eq!(
+            parse_args(&args("trajectory 2 --part 1 --format svg")),
+            Ok(Command::Trajectory {
+                part: Part::One,
+                input: None,
+                format: Export::Svg
+            })
+        );
+        assert_eq!(
+            parse_args(&args("animate 11 --delay 20 --paused")),
+            Ok(Command::Animate {
+                day: 11,
+                input: None,
+                delay: Duration::from_millis(20),
+                paused: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 26")).is_err());
+        assert!(parse_args(&args("run 0")).is_err());
+        assert!(parse_args(&args("run 1 --part 3")).is_err());
+        assert!(parse_args(&args("walk 1")).is_err());
+        assert!(parse_args(&args("run all --input day_01.txt")).is_err());
+        assert!(parse_args(&args("verify")).is_err());
+        assert!(parse_args(&args("verify 1 --part 1")).is_err());
+        assert!(parse_args(&args("animate 1")).is_err());
+        assert!(parse_args(&args("animate all")).is_err());
+        assert!(parse_args(&args("animate 11 --part 1")).is_err());
+        assert!(parse_args(&args("run 11 --paused")).is_err());
+        assert!(parse_args(&args("run 1 --format xml")).is_err());
+        assert!(parse_args(&args("new 26")).is_err());
+        assert!(parse_args(&args("new 5 6")).is_err());
+        assert!(parse_args(&args("verify 1 --format json")).is_err());
+        assert!(parse_args(&args("trajectory 3")).is_err());
+        assert!(parse_args(&args("trajectory 2 --format table")).is_err());
+    }
+}
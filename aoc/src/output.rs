@@ -0,0 +1,183 @@
+use std::{fmt::Write as _, time::Duration};
+
+use common::{Answer, Part};
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    /// A single array holding every result, printed once all days are done
+    Json,
+    /// One object per line, printed as soon as each part is solved
+    JsonLines,
+}
+
+impl Format {
+    pub fn parse(raw: &str) -> Option<Format> {
+        match raw {
+            "table" => Some(Format::Table),
+            "json" => Some(Format::Json),
+            "jsonl" => Some(Format::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of solving one part of one day.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    #[serde(serialize_with = "serialize_part")]
+    pub part: Part,
+    #[serde(serialize_with = "serialize_answer")]
+    pub answer: Answer,
+    pub elapsed_ms: f64,
+    /// Hex SHA-256 of the raw input, to tell results for different inputs apart
+    pub input_sha256: String,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, answer: Answer, elapsed: Duration, input_sha256: &str) -> Self {
+        Self {
+            day,
+            part,
+            answer,
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            input_sha256: input_sha256.to_string(),
+        }
+    }
+}
+
+fn serialize_part<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    match part {
+        Part::One => serializer.serialize_u8(1),
+        Part::Two => serializer.serialize_u8(2),
+    }
+}
+
+fn serialize_answer<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Number(n) => serializer.serialize_i64(*n),
+        Answer::Text(text) => serializer.serialize_str(text),
+    }
+}
+
+pub fn sha256(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{:02x}", byte).unwrap();
+            hex
+        })
+}
+
+/// Prints records in the chosen format as they come in.
+#[derive(Debug)]
+pub struct Report {
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Report {
+    pub fn new(format: Format) -> Self {
+        if format == Format::Table {
+            println!(
+                "{:>3} | {:>4} | {:>20} | {:>10}",
+                "Day", "Part", "Answer", "Time"
+            );
+            println!("{:-<4}+{:-<6}+{:-<22}+{:-<11}", "", "", "", "");
+        }
+
+        Self {
+            format,
+            records: vec![],
+        }
+    }
+
+    pub fn push(&mut self, record: Record) {
+        match self.format {
+            Format::Table => print_row(&record),
+            Format::Json => self.records.push(record),
+            Format::JsonLines => println!("{}", to_json(&record)),
+        }
+    }
+
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            println!("{}", to_json(&self.records));
+        }
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value).expect("records only hold plain values")
+}
+
+fn print_row(record: &Record) {
+    let Record {
+        day, part, answer, ..
+    } = record;
+    let elapsed = Duration::from_secs_f64(record.elapsed_ms / 1000.0);
+
+    match answer {
+        // Multi-line answers (e.g. rendered letters) go below their row
+        Answer::Text(text) if text.contains('\n') => {
+            println!("{:>3} | {:>4} | {:>20} | {:>10.2?}", day, part, "", elapsed);
+            for line in text.lines() {
+                println!("      {}", line);
+            }
+        }
+        answer => {
+            let answer = answer.to_string();
+            println!(
+                "{:>3} | {:>4} | {:>20} | {:>10.2?}",
+                day, part, answer, elapsed
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::{Answer, Part};
+
+    use crate::output::{sha256, to_json, Format, Record};
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_record_json() {
+        let record = Record::new(
+            13,
+            Part::Two,
+            Answer::Text("#.\n.#".to_string()),
+            Duration::from_micros(1500),
+            "abc",
+        );
+        assert_eq!(
+            to_json(&record),
+            r##"{"day":13,"part":2,"answer":"#.\n.#","elapsed_ms":1.5,"input_sha256":"abc"}"##
+        );
+
+        let record = Record::new(1, Part::One, Answer::Number(7), Duration::ZERO, "abc");
+        assert_eq!(
+            to_json(&[record]),
+            r#"[{"day":1,"part":1,"answer":7,"elapsed_ms":0.0,"input_sha256":"abc"}]"#
+        );
+    }
+
+    #[test]
+    fn test_format_parse() {
+        assert_eq!(Format::parse("jsonl"), Some(Format::JsonLines));
+        assert_eq!(Format::parse("csv"), None);
+    }
+}
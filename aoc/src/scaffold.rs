@@ -0,0 +1,135 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/lib.rs");
+const MAIN_RS: &str = include_str!("../templates/main.rs");
+
+/// Creates the `day_NN` crate inside the workspace at `root` from the templates, with empty
+/// inputs to paste the puzzle input and example into, and adds it to the workspace members.
+/// Returns the created files.
+pub fn create(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let name = format!("day_{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest =
+        register_member(&fs::read_to_string(&manifest_path)?, &name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no workspace members in {}", manifest_path.display()),
+            )
+        })?;
+
+    let files = [
+        ("Cargo.toml", fill(CARGO_TOML, day)),
+        ("src/lib.rs", fill(LIB_RS, day)),
+        ("src/main.rs", fill(MAIN_RS, day)),
+        ("src/input.txt", String::new()),
+        ("src/test.input.txt", String::new()),
+    ];
+
+    fs::create_dir_all(dir.join("src"))?;
+    let mut created = vec![];
+    for (file, contents) in files {
+        let path = dir.join(file);
+        fs::write(&path, contents)?;
+        created.push(path);
+    }
+    fs::write(&manifest_path, manifest)?;
+
+    Ok(created)
+}
+
+/// Puts the day into a template written for day 0.
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("day_00", &format!("day_{:02}", day))
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping the list sorted.
+fn register_member(manifest: &str, member: &str) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find(']')?;
+    let entry = format!("    \"{}\",\n", member);
+
+    // Each member sits on its own line
+    let mut offset = start + manifest[start..].find('\n')? + 1;
+    while offset < end {
+        let line_end = offset + manifest[offset..].find('\n')? + 1;
+        if manifest[offset..line_end].trim().trim_end_matches(',')
+            > format!("\"{}\"", member).as_str()
+        {
+            break;
+        }
+        offset = line_end;
+    }
+
+    Some(format!(
+        "{}{}{}",
+        &manifest[..offset],
+        entry,
+        &manifest[offset..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use crate::scaffold::{create, fill, register_member};
+
+    #[test]
+    fn test_register_member() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n    \"grid\",\n]\n";
+        assert_eq!(
+            register_member(manifest, "day_02").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n    \"day_02\",\n    \"grid\",\n]\n"
+        );
+        assert_eq!(
+            register_member(manifest, "search").unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_01\",\n    \"grid\",\n    \"search\",\n]\n"
+        );
+        assert_eq!(register_member("[package]\n", "day_02"), None);
+    }
+
+    #[test]
+    fn test_fill() {
+        let filled = fill("use day_00::Day00;\nconst DAY: u8 = 0;", 7);
+        assert_eq!(filled, "use day_07::Day07;\nconst DAY: u8 = 7;");
+    }
+
+    #[test]
+    fn test_create() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n]\n",
+        )
+        .unwrap();
+
+        let created = create(&root, 9).unwrap();
+        assert_eq!(created.len(), 5);
+        let lib = fs::read_to_string(root.join("day_09/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day09;") && lib.contains("const DAY: u8 = 9;"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day_09\""));
+
+        // Never overwrites an existing day
+        assert!(create(&root, 9).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,44 @@
+use common::{parse::ParseError, Solution};
+use grid::Grid;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(_map: &Self::Input) -> Self::Part1 {
+        todo!()
+    }
+
+    fn part2(_map: &Self::Input) -> Self::Part2 {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::Day00;
+
+    // The example from the puzzle text, with the answers given there
+    #[test]
+    fn test_part1() {
+        let map = Day00::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day00::part1(&map), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = Day00::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day00::part2(&map), 0);
+    }
+}
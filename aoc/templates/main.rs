@@ -0,0 +1,14 @@
+use common::{input, trace, Solution};
+use day_00::Day00;
+
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day00::DAY, env!("CARGO_MANIFEST_DIR"));
+    let map = common::parse_or_exit::<Day00>(&input);
+
+    let part1 = trace::part1::<Day00>(&map);
+    println!("Part 1: {}", part1);
+
+    let part2 = trace::part2::<Day00>(&map);
+    println!("Part 2: {}", part2);
+}
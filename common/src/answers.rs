@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use crate::{parse, parse::ParseError, Answer, Part};
+
+/// Known answers, keyed by day, part and the name of the input they belong to.
+///
+/// Each entry is a line `<day> <part> <input> <answer>`, e.g. `01 2 input 1608`. Multi-line
+/// answers leave the answer empty and follow with one `|`-prefixed line per answer line.
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: HashMap<(u8, Part, String), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut entries = HashMap::new();
+        let mut last = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+
+            if let Some(continuation) = line.strip_prefix('|') {
+                let answer: &mut String = last
+                    .as_ref()
+                    .and_then(|key| entries.get_mut(key))
+                    .ok_or_else(|| ParseError::at(line_number, line, line, "answer entry"))?;
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(continuation);
+                continue;
+            }
+
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = content.split_whitespace();
+            let day = parse::number(line_number, line, tokens.next().unwrap_or(content))?;
+            let part = match tokens.next() {
+                Some("1") => Part::One,
+                Some("2") => Part::Two,
+                Some(other) => {
+                    return Err(ParseError::at(line_number, line, other, "part 1 or 2"));
+                }
+                None => return Err(ParseError::end_of_line(line_number, line, "part")),
+            };
+            let input = tokens
+                .next()
+                .ok_or_else(|| ParseError::end_of_line(line_number, line, "input name"))?;
+            let answer = tokens.collect::<Vec<_>>().join(" ");
+
+            let key = (day, part, input.to_string());
+            if entries.insert(key.clone(), answer).is_some() {
+                return Err(ParseError::at(line_number, line, content, "a new entry"));
+            }
+            last = Some(key);
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.entries
+            .get(&(day, part, input.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &str, answer: &Answer) -> Verdict {
+        match self.get(day, part, input) {
+            Some(expected) if normalize(expected) == normalize(&answer.to_string()) => {
+                Verdict::Pass
+            }
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+// Rendered answers may differ in trailing whitespace only
+fn normalize(answer: &str) -> Vec<&str> {
+    answer.trim_end().lines().map(str::trim_end).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::{Answers, Verdict},
+        parse::ParseError,
+        Answer, Part,
+    };
+
+    #[test]
+    fn test_parse_and_check() {
+        let answers =
+            Answers::parse("# day part input answer\n01 1 input 1557\n\n13 2 input\n| ##\n|#  #\n")
+                .unwrap();
+
+        assert_eq!(answers.get(1, Part::One, "input"), Some("1557"));
+        assert_eq!(answers.get(13, Part::Two, "input"), Some(" ##\n#  #"));
+        assert_eq!(
+            answers.check(1, Part::One, "input", &Answer::Number(1557)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(1, Part::One, "input", &Answer::Number(7)),
+            Verdict::Fail {
+                expected: "1557".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, "input", &Answer::Number(7)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            answers.check(
+                13,
+                Part::Two,
+                "input",
+                &Answer::Text(" ##  \n#  #\n".into())
+            ),
+            Verdict::Pass
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("01 3 input 1"),
+            Err(ParseError::new(1, 4, "part 1 or 2", "3"))
+        );
+        assert_eq!(
+            Answers::parse("01 1"),
+            Err(ParseError::new(1, 5, "input name", ""))
+        );
+        assert_eq!(
+            Answers::parse("| ##"),
+            Err(ParseError::new(1, 1, "answer entry", "| ##"))
+        );
+    }
+}
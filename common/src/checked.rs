@@ -0,0 +1,72 @@
+//! The overflow policy: sums and counts that can outgrow their integer type are computed with
+//! checked arithmetic and fail with [`Overflow`] instead of wrapping or panicking.
+
+use std::fmt::Display;
+
+/// A value that does not fit the integer type it is computed in, naming what it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow(pub &'static str);
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} out of range", self.0)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Counter for simulations whose counts grow exponentially. `u64` overflows after a while,
+/// [`Big`] (with the `bigint` feature) never does.
+pub trait Count: Clone + Ord + Display + From<u64> {
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+}
+
+impl Count for u64 {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u64::checked_add(*self, *other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        u64::checked_sub(*self, *other)
+    }
+}
+
+/// Arbitrary-precision count.
+#[cfg(feature = "bigint")]
+pub type Big = ibig::UBig;
+
+#[cfg(feature = "bigint")]
+impl Count for Big {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (self >= other).then(|| self - other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checked::{Count, Overflow};
+
+    #[test]
+    fn test_u64_count() {
+        assert_eq!(Count::checked_add(&u64::MAX, &0), Some(u64::MAX));
+        assert_eq!(Count::checked_add(&u64::MAX, &1), None);
+        assert_eq!(Count::checked_sub(&1u64, &2), None);
+        assert_eq!(Overflow("depth").to_string(), "depth out of range");
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_big_count() {
+        use crate::checked::Big;
+
+        let max = Big::from(u64::MAX);
+        let sum = max.checked_add(&Big::from(1u64)).unwrap();
+        assert_eq!(sum.to_string(), "18446744073709551616");
+        assert_eq!(Big::from(1u64).checked_sub(&max), None);
+    }
+}
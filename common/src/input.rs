@@ -0,0 +1,166 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Picks the input for `day`, in order of precedence: an explicit path (`-` meaning stdin),
+    /// `<input_dir>/day_NN.txt` and finally `src/input.txt` inside the day's crate.
+    pub fn resolve(
+        arg: Option<&str>,
+        input_dir: Option<PathBuf>,
+        day: u8,
+        manifest_dir: &Path,
+    ) -> Self {
+        match (arg, input_dir) {
+            (Some("-"), _) => Source::Stdin,
+            (Some(path), _) => Source::File(PathBuf::from(path)),
+            (None, Some(dir)) => Source::File(dir.join(format!("day_{:02}.txt", day))),
+            (None, None) => Source::File(manifest_dir.join("src").join("input.txt")),
+        }
+    }
+
+    /// Same as [`Source::resolve`], with the directory taken from `AOC_INPUT_DIR`.
+    pub fn from_env(arg: Option<&str>, day: u8, manifest_dir: &Path) -> Self {
+        let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        Self::resolve(arg, input_dir, day, manifest_dir)
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::File(path) => fs::read_to_string(path).map_err(|error| match error.kind() {
+                io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+                _ => InputError::Io(path.clone(), error),
+            }),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the input for reading line by line, for days that stream it instead of loading
+    /// all of it into memory.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::File(path) => {
+                let file = fs::File::open(path).map_err(|error| match error.kind() {
+                    io::ErrorKind::NotFound => InputError::NotFound(path.clone()),
+                    _ => InputError::Io(path.clone(), error),
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(
+                f,
+                "Input file {} does not exist, pass a path (or - for stdin) or set {}",
+                path.display(),
+                INPUT_DIR_VAR
+            ),
+            InputError::Io(path, error) => {
+                write!(f, "Could not read {}: {}", path.display(), error)
+            }
+            InputError::Stdin(error) => write!(f, "Could not read stdin: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads the input for a day's own binary, taking the path from its first argument.
+/// Exits with a readable message instead of panicking when the input cannot be read.
+pub fn load_or_exit(day: u8, manifest_dir: &str) -> String {
+    let arg = env::args().nth(1);
+    let source = Source::from_env(arg.as_deref(), day, Path::new(manifest_dir));
+
+    source.read().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    })
+}
+
+/// Same as [`load_or_exit`], but only opens the input so that it can be streamed.
+pub fn open_or_exit(day: u8, manifest_dir: &str) -> Box<dyn BufRead> {
+    let arg = env::args().nth(1);
+    let source = Source::from_env(arg.as_deref(), day, Path::new(manifest_dir));
+
+    source.open().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::input::{InputError, Source};
+
+    #[test]
+    fn test_resolve() {
+        let manifest_dir = Path::new("day_07");
+        let input_dir = Some(PathBuf::from("inputs"));
+
+        assert_eq!(
+            Source::resolve(Some("-"), input_dir.clone(), 7, manifest_dir),
+            Source::Stdin
+        );
+        assert_eq!(
+            Source::resolve(Some("other.txt"), input_dir.clone(), 7, manifest_dir),
+            Source::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            Source::resolve(None, input_dir, 7, manifest_dir),
+            Source::File(PathBuf::from("inputs/day_07.txt"))
+        );
+        assert_eq!(
+            Source::resolve(None, None, 7, manifest_dir),
+            Source::File(PathBuf::from("day_07/src/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = Source::File(PathBuf::from("does/not/exist.txt"));
+        assert!(matches!(source.read(), Err(InputError::NotFound(_))));
+        assert!(matches!(source.open(), Err(InputError::NotFound(_))));
+    }
+}
@@ -0,0 +1,298 @@
+use std::{fmt::Display, process};
+
+use checked::Overflow;
+use parse::ParseError;
+
+pub mod answers;
+pub mod checked;
+pub mod input;
+pub mod parse;
+pub mod trace;
+
+/// A single day's puzzle, split into parsing and the two parts so that it can be driven
+/// from its own `main` as well as from the `aoc` runner.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: IntoAnswer;
+    type Part2: IntoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    // Too large for a number, but still a correct answer
+                    i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u32, i32, u64, i64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<checked::Big> for Answer {
+    fn from(n: checked::Big) -> Self {
+        i64::try_from(&n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+    }
+}
+
+/// A well-formed input that a part still finds no answer in, saying what is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer(pub &'static str);
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no answer, {}", self.0)
+    }
+}
+
+impl std::error::Error for NoAnswer {}
+
+/// What a part can return: anything that converts into an [`Answer`], or a result that might
+/// have overflowed or found no answer.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, SolveError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, SolveError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: Into<SolveError>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, SolveError> {
+        self.map(Into::into).map_err(Into::into)
+    }
+}
+
+/// Why a day could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow),
+    NoAnswer(NoAnswer),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "malformed input, {}", error),
+            SolveError::Overflow(error) => write!(f, "{}", error),
+            SolveError::NoAnswer(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(error: Overflow) -> Self {
+        SolveError::Overflow(error)
+    }
+}
+
+impl From<NoAnswer> for SolveError {
+    fn from(error: NoAnswer) -> Self {
+        SolveError::NoAnswer(error)
+    }
+}
+
+/// Type-erased handle to a [`Solution`], so the runner can keep every day in one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    run: fn(&str, Part) -> Result<Answer, SolveError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            run: run::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, part: Part) -> Result<Answer, SolveError> {
+        (self.run)(input, part)
+    }
+}
+
+/// Every solved day as a [`Day`], in order. Expands in the calling crate, which has to depend
+/// on all the day crates.
+#[macro_export]
+macro_rules! days {
+    () => {
+        vec![
+            $crate::Day::of::<day_01::Day01>(),
+            $crate::Day::of::<day_02::Day02>(),
+            $crate::Day::of::<day_03::Day03>(),
+            $crate::Day::of::<day_04::Day04>(),
+            $crate::Day::of::<day_05::Day05>(),
+            $crate::Day::of::<day_06::Day06>(),
+            $crate::Day::of::<day_07::Day07>(),
+            $crate::Day::of::<day_08::Day08>(),
+            $crate::Day::of::<day_09::Day09>(),
+            $crate::Day::of::<day_10::Day10>(),
+            $crate::Day::of::<day_11::Day11>(),
+            $crate::Day::of::<day_12::Day12>(),
+            $crate::Day::of::<day_13::Day13>(),
+            $crate::Day::of::<day_14::Day14>(),
+            $crate::Day::of::<day_15::Day15>(),
+            $crate::Day::of::<day_16::Day16>(),
+            $crate::Day::of::<day_17::Day17>(),
+            $crate::Day::of::<day_18::Day18>(),
+            $crate::Day::of::<day_19::Day19>(),
+            $crate::Day::of::<day_20::Day20>(),
+            $crate::Day::of::<day_21::Day21>(),
+            $crate::Day::of::<day_22::Day22>(),
+            $crate::Day::of::<day_23::Day23>(),
+            $crate::Day::of::<day_24::Day24>(),
+            $crate::Day::of::<day_25::Day25>(),
+        ]
+    };
+}
+
+fn run<S: Solution>(input: &str, part: Part) -> Result<Answer, SolveError> {
+    let input = trace::parse::<S>(input)?;
+    let answer = match part {
+        Part::One => trace::part1::<S>(&input).into_answer()?,
+        Part::Two => trace::part2::<S>(&input).into_answer()?,
+    };
+    Ok(answer)
+}
+
+/// Parses the input for a day's own binary, exiting with the error position on malformed input.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Input {
+    trace::parse::<S>(input).unwrap_or_else(|error| {
+        eprintln!("Malformed input for day {}: {}", S::DAY, error);
+        process::exit(1);
+    })
+}
+
+/// The answer to a part for a day's own binary, exiting when it overflowed or found none.
+pub fn answer_or_exit<S: Solution>(part: Part, result: impl IntoAnswer) -> Answer {
+    result.into_answer().unwrap_or_else(|error| {
+        eprintln!("Day {} part {}: {}", S::DAY, part, error);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        checked::Overflow, parse, parse::ParseError, Answer, Day, IntoAnswer, NoAnswer, Part,
+        Solution, SolveError,
+    };
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<usize>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, l)| parse::number(i + 1, l, l))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            format!("{} numbers", input.len())
+        }
+    }
+
+    #[test]
+    fn test_day_dispatch() {
+        let day = Day::of::<Sum>();
+        assert_eq!(day.number, 0);
+        assert_eq!(day.run("1\n2\n3", Part::One), Ok(Answer::Number(6)));
+        assert_eq!(
+            day.run("1\n2\n3", Part::Two),
+            Ok(Answer::Text("3 numbers".to_string()))
+        );
+        assert_eq!(
+            day.run("1\nx", Part::One),
+            Err(SolveError::Parse(ParseError::new(2, 1, "number", "x")))
+        );
+    }
+
+    #[test]
+    fn test_into_answer() {
+        assert_eq!(7u32.into_answer(), Ok(Answer::Number(7)));
+        assert_eq!(
+            u64::MAX.into_answer(),
+            Ok(Answer::Text("18446744073709551615".to_string()))
+        );
+        let overflowed: Result<u64, Overflow> = Err(Overflow("fuel"));
+        assert_eq!(
+            overflowed.into_answer(),
+            Err(SolveError::Overflow(Overflow("fuel")))
+        );
+        let missing: Result<u64, NoAnswer> = Err(NoAnswer("no crabs"));
+        assert_eq!(
+            missing.into_answer().unwrap_err().to_string(),
+            "no answer, no crabs"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Malformed puzzle input, pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line within the input
+    pub line: usize,
+    /// 1-based character column within the line
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Error for `token`, which has to be a subslice of `line` so that its column can be derived.
+    pub fn at(line_number: usize, line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| *offset <= line.len())
+            .unwrap_or(0);
+        let column = line[..offset].chars().count() + 1;
+
+        Self::new(line_number, column, expected, token)
+    }
+
+    /// Error for input that stops before `expected` shows up on `line`.
+    pub fn end_of_line(line_number: usize, line: &str, expected: impl Into<String>) -> Self {
+        Self::new(line_number, line.chars().count() + 1, expected, "")
+    }
+
+    /// Moves the error down by `lines`, for parsers that only see a block of the whole input.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found '{}'", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token` (a subslice of `line`) as a number.
+pub fn number<T: FromStr>(line_number: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line_number, line, token, "number"))
+}
+
+/// Splits the input at blank lines, yielding each block with the 0-based index of its first line.
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some((index, offset)),
+            (true, Some((first, begin))) => {
+                blocks.push((first, input[begin..offset].trim_end()));
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    if let Some((first, begin)) = start {
+        blocks.push((first, input[begin..].trim_end()));
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{blocks, number, ParseError};
+
+    #[test]
+    fn test_error_position() {
+        let line = "0,9 -> 5,x";
+        let error = number::<u32>(3, line, &line[9..]).unwrap_err();
+        assert_eq!(error, ParseError::new(3, 10, "number", "x"));
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 10: expected number, found 'x'"
+        );
+
+        let error = ParseError::end_of_line(1, "fold along", "x or y");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected x or y, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1,2\n\n\na\nb\n\nc\n";
+        assert_eq!(blocks(input), vec![(0, "1,2"), (3, "a\nb"), (6, "c")]);
+    }
+}
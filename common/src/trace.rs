@@ -0,0 +1,103 @@
+//! Spans around parsing and solving, and the subscriber that prints them.
+//!
+//! Every day emits `debug` events with counters for its core loop, for example the heap pops
+//! of a search or the reductions of a snailfish sum. Nothing is printed unless [`TRACE_VAR`]
+//! is set to a filter such as `debug` or `search=debug,day_18=trace`. Events are filed under
+//! the crate that emits them, which is `search` for the shared searches, so a single day is
+//! best picked by the `day` field of its spans: `[{day=15}]=debug`.
+
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use tracing::info_span;
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+use crate::{parse::ParseError, Solution};
+
+pub const TRACE_VAR: &str = "AOC_TRACE";
+/// `compact` (the default), `pretty` or `json`.
+pub const FORMAT_VAR: &str = "AOC_TRACE_FORMAT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Compact,
+    Pretty,
+    Json,
+}
+
+impl Format {
+    pub fn parse(raw: &str) -> Option<Format> {
+        match raw {
+            "compact" => Some(Format::Compact),
+            "pretty" => Some(Format::Pretty),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Installs a subscriber writing to stderr, configured by [`TRACE_VAR`] and [`FORMAT_VAR`].
+/// Does nothing when [`TRACE_VAR`] is not set, or when another subscriber is installed
+/// already.
+pub fn init() {
+    let Ok(filter) = env::var(TRACE_VAR) else {
+        return;
+    };
+    let format = match env::var(FORMAT_VAR) {
+        Ok(raw) => Format::parse(&raw).unwrap_or_else(|| {
+            eprintln!("Unknown {} '{}', using compact", FORMAT_VAR, raw);
+            Format::Compact
+        }),
+        Err(_) => Format::Compact,
+    };
+    init_with(&filter, format);
+}
+
+/// Installs a subscriber writing to stderr that only shows what `filter` lets through. Closed
+/// spans are printed with their busy and idle time.
+pub fn init_with(filter: &str, format: Format) {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new(filter))
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(io::stderr().is_terminal())
+        .with_writer(io::stderr);
+
+    // Keep whatever subscriber was there first
+    let _ = match format {
+        Format::Compact => builder.compact().try_init(),
+        Format::Pretty => builder.pretty().try_init(),
+        Format::Json => builder.json().try_init(),
+    };
+}
+
+/// [`Solution::parse`] inside a `parse` span.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    let _span = info_span!("parse", day = S::DAY).entered();
+    S::parse(input)
+}
+
+/// [`Solution::part1`] inside a `part1` span.
+pub fn part1<S: Solution>(input: &S::Input) -> S::Part1 {
+    let _span = info_span!("part1", day = S::DAY).entered();
+    S::part1(input)
+}
+
+/// [`Solution::part2`] inside a `part2` span.
+pub fn part2<S: Solution>(input: &S::Input) -> S::Part2 {
+    let _span = info_span!("part2", day = S::DAY).entered();
+    S::part2(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trace::Format;
+
+    #[test]
+    fn test_format_parse() {
+        assert_eq!(Format::parse("json"), Some(Format::Json));
+        assert_eq!(Format::parse("pretty"), Some(Format::Pretty));
+        assert_eq!(Format::parse("plain"), None);
+    }
+}
@@ -0,0 +1,592 @@
+use std::{
+    cmp::Ordering,
+    collections::VecDeque,
+    fmt::{Debug, Display},
+    io::{self, BufRead},
+    marker::PhantomData,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_depths(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        count_increases(input.iter().copied(), 1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        count_increases(input.iter().copied(), 3)
+    }
+}
+
+/// A depth measurement, integer or float, along with the type its window sums are kept in.
+pub trait Depth: Copy + FromStr {
+    /// Wide enough that no window of integer depths can overflow it
+    type Sum: Copy
+        + PartialOrd
+        + Debug
+        + Display
+        + Default
+        + Add<Output = Self::Sum>
+        + Sub<Output = Self::Sum>;
+
+    fn widen(self) -> Self::Sum;
+
+    /// Sum of the window, given the sum kept running alongside it. That one is exact for
+    /// integers, while floats add the window up again so that rounding errors can't creep in.
+    fn sum(_window: &VecDeque<Self>, running: Self::Sum) -> Self::Sum {
+        running
+    }
+
+    /// Whether the depth can be compared at all, which rules out NaN and infinities.
+    fn is_valid(self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_depth {
+    ($sum:ty: $($t:ty),*) => {
+        $(
+            impl Depth for $t {
+                type Sum = $sum;
+
+                fn widen(self) -> $sum {
+                    self as $sum
+                }
+            }
+        )*
+    };
+}
+
+impl_depth!(u128: u8, u16, u32, u64, usize);
+impl_depth!(i128: i8, i16, i32, i64, isize);
+
+impl Depth for f32 {
+    type Sum = f64;
+
+    fn widen(self) -> f64 {
+        self as f64
+    }
+
+    fn sum(window: &VecDeque<Self>, _: f64) -> f64 {
+        window.iter().map(|depth| depth.widen()).sum()
+    }
+
+    fn is_valid(self) -> bool {
+        self.is_finite()
+    }
+}
+
+impl Depth for f64 {
+    type Sum = f64;
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn sum(window: &VecDeque<Self>, _: f64) -> f64 {
+        window.iter().map(|depth| depth.widen()).sum()
+    }
+
+    fn is_valid(self) -> bool {
+        self.is_finite()
+    }
+}
+
+/// Parses one depth per line.
+pub fn parse_depths<D: Depth>(input: &str) -> Result<Vec<D>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| depth(i + 1, l))
+        .collect()
+}
+
+fn depth<D: Depth>(line_number: usize, line: &str) -> Result<D, ParseError> {
+    let token = line.trim();
+    let depth: D = parse::number(line_number, line, token)?;
+    if depth.is_valid() {
+        Ok(depth)
+    } else {
+        Err(ParseError::at(line_number, line, token, "finite number"))
+    }
+}
+
+/// Follows the sums of the last `size` depths, one depth at a time, collecting a [`Report`] on
+/// how they change. Only the depths inside the window are kept, so it runs in constant memory
+/// on inputs of any length.
+#[derive(Debug, Clone)]
+pub struct SlidingWindow<D: Depth = u64> {
+    size: usize,
+    depths: VecDeque<D>,
+    sum: D::Sum,
+    /// Index of the first window of the increasing or decreasing run still going on
+    rising: Option<u64>,
+    falling: Option<u64>,
+    report: Report<D::Sum>,
+}
+
+/// How the sums of a sliding window changed over a whole input. Windows are numbered from 0,
+/// window `i` covering depths `i` to `i + window_size - 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report<S> {
+    pub window_size: usize,
+    pub windows: u64,
+    pub increases: u64,
+    pub decreases: u64,
+    pub plateaus: u64,
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    pub largest_jump: Option<Jump<S>>,
+}
+
+/// Consecutive windows, each with a strictly larger (or smaller) sum than the one before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl Run {
+    /// Number of windows in the run, including the one it starts from.
+    pub fn windows(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
+/// Change in sum from window `window - 1` to `window`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump<S> {
+    pub window: u64,
+    pub from: S,
+    pub to: S,
+}
+
+impl<S: Copy + PartialOrd + Sub<Output = S>> Jump<S> {
+    /// How far the sum moved, in either direction.
+    pub fn distance(&self) -> S {
+        if self.to > self.from {
+            self.to - self.from
+        } else {
+            self.from - self.to
+        }
+    }
+}
+
+impl<D: Depth> SlidingWindow<D> {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "window size has to be at least 1");
+        Self {
+            size,
+            depths: VecDeque::with_capacity(size + 1),
+            sum: D::Sum::default(),
+            rising: None,
+            falling: None,
+            report: Report {
+                window_size: size,
+                windows: 0,
+                increases: 0,
+                decreases: 0,
+                plateaus: 0,
+                longest_increase: None,
+                longest_decrease: None,
+                largest_jump: None,
+            },
+        }
+    }
+
+    /// Adds the next depth, returning the sums of the previous and the new window once there
+    /// is one to compare against.
+    pub fn push(&mut self, depth: D) -> Option<(D::Sum, D::Sum)> {
+        debug_assert!(depth.is_valid());
+        let previous = self.sum;
+        self.depths.push_back(depth);
+        self.sum = self.sum + depth.widen();
+
+        let mut change = None;
+        if self.depths.len() > self.size {
+            let oldest = self.depths.pop_front().unwrap();
+            self.sum = D::sum(&self.depths, self.sum - oldest.widen());
+            // Both windows share everything but these two, so comparing them is exact even
+            // where the sums are not
+            self.compare(previous, depth.widen().partial_cmp(&oldest.widen()));
+            change = Some((previous, self.sum));
+        } else {
+            self.sum = D::sum(&self.depths, self.sum);
+        }
+        if self.depths.len() == self.size {
+            self.report.windows += 1;
+        }
+        change
+    }
+
+    /// Compares the window that was just completed with the one before.
+    fn compare(&mut self, previous: D::Sum, ordering: Option<Ordering>) {
+        let report = &mut self.report;
+        let window = report.windows;
+
+        match ordering {
+            Some(Ordering::Greater) => {
+                report.increases += 1;
+                self.falling = None;
+                extend(&mut self.rising, &mut report.longest_increase, window);
+            }
+            Some(Ordering::Less) => {
+                report.decreases += 1;
+                self.rising = None;
+                extend(&mut self.falling, &mut report.longest_decrease, window);
+            }
+            _ => {
+                report.plateaus += 1;
+                self.rising = None;
+                self.falling = None;
+            }
+        }
+
+        let jump = Jump {
+            window,
+            from: previous,
+            to: self.sum,
+        };
+        if report
+            .largest_jump
+            .is_none_or(|largest| jump.distance() > largest.distance())
+        {
+            report.largest_jump = Some(jump);
+        }
+    }
+
+    /// Number of windows whose sum is larger than the one before.
+    pub fn increases(&self) -> u64 {
+        self.report.increases
+    }
+
+    pub fn report(&self) -> Report<D::Sum> {
+        self.report
+    }
+}
+
+/// Continues the run still going on, or starts one at the window before `window`, keeping the
+/// first of equally long runs as the longest.
+fn extend(current: &mut Option<u64>, longest: &mut Option<Run>, window: u64) {
+    let run = Run {
+        start: *current.get_or_insert(window - 1),
+        end: window,
+    };
+    if longest.is_none_or(|longest| run.windows() > longest.windows()) {
+        *longest = Some(run);
+    }
+}
+
+impl<S: Display> Display for Report<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "windows of {}: {} windows, {} increased, {} decreased, {} stayed the same",
+            self.window_size, self.windows, self.increases, self.decreases, self.plateaus
+        )?;
+        if let Some(run) = self.longest_increase {
+            write!(
+                f,
+                "\n  longest increase: windows {} to {}",
+                run.start, run.end
+            )?;
+        }
+        if let Some(run) = self.longest_decrease {
+            write!(
+                f,
+                "\n  longest decrease: windows {} to {}",
+                run.start, run.end
+            )?;
+        }
+        if let Some(jump) = &self.largest_jump {
+            write!(
+                f,
+                "\n  largest jump: {} to {} into window {}",
+                jump.from, jump.to, jump.window
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the increases between sums of `window_size` consecutive depths.
+pub fn count_increases<D: Depth>(depths: impl IntoIterator<Item = D>, window_size: usize) -> u64 {
+    let mut window = SlidingWindow::new(window_size);
+    for depth in depths {
+        window.push(depth);
+    }
+
+    let report = window.report();
+    tracing::debug!(
+        window_size,
+        windows = report.windows,
+        increases = report.increases
+    );
+    report.increases
+}
+
+/// Counts the changes between sums of `window_size` consecutive depths that `counts` accepts,
+/// given the previous and the next sum.
+pub fn count_changes<D, F>(
+    depths: impl IntoIterator<Item = D>,
+    window_size: usize,
+    counts: F,
+) -> u64
+where
+    D: Depth,
+    F: Fn(D::Sum, D::Sum) -> bool,
+{
+    let mut window = SlidingWindow::new(window_size);
+    let changes = depths
+        .into_iter()
+        .filter_map(|depth| window.push(depth))
+        .filter(|&(previous, next)| counts(previous, next))
+        .count() as u64;
+
+    tracing::debug!(window_size, windows = window.report().windows, changes);
+    changes
+}
+
+/// Predicate for [`count_changes`] accepting sums that grew by `amount` or more.
+pub fn rise_of_at_least<S>(amount: S) -> impl Fn(S, S) -> bool
+where
+    S: Copy + PartialOrd + Add<Output = S>,
+{
+    move |previous, next| next >= previous + amount
+}
+
+/// Reports on every one of `window_sizes` in a single pass over `depths`.
+pub fn reports<D: Depth>(
+    depths: impl IntoIterator<Item = D>,
+    window_sizes: &[usize],
+) -> Vec<Report<D::Sum>> {
+    let mut windows = window_sizes
+        .iter()
+        .map(|&size| SlidingWindow::new(size))
+        .collect::<Vec<_>>();
+    for depth in depths {
+        windows.iter_mut().for_each(|window| {
+            window.push(depth);
+        });
+    }
+    windows.iter().map(SlidingWindow::report).collect()
+}
+
+/// Why a depth could not be read from a stream.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(error) => write!(f, "{}", error),
+            ReadError::Parse(error) => write!(f, "malformed input, {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Parses one depth per line from `reader`, reusing a single line buffer throughout.
+pub fn depths<D: Depth, R: BufRead>(reader: R) -> Depths<R, D> {
+    Depths {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        depth: PhantomData,
+    }
+}
+
+pub struct Depths<R, D> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    depth: PhantomData<D>,
+}
+
+impl<R: BufRead, D: Depth> Iterator for Depths<R, D> {
+    type Item = Result<D, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.clear();
+        match self.reader.read_line(&mut self.line) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.line_number += 1;
+                let line = self.line.trim_end_matches(['\n', '\r']);
+                Some(depth(self.line_number, line).map_err(ReadError::Parse))
+            }
+            Err(error) => Some(Err(ReadError::Io(error))),
+        }
+    }
+}
+
+/// Feeds every depth from `reader` into all of `windows` in a single pass.
+pub fn scan<D: Depth, R: BufRead>(
+    reader: R,
+    windows: &mut [SlidingWindow<D>],
+) -> Result<(), ReadError> {
+    for depth in depths(reader) {
+        let depth = depth?;
+        windows.iter_mut().for_each(|window| {
+            window.push(depth);
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse::ParseError, Solution};
+
+    use crate::{
+        count_changes, count_increases, parse_depths, reports, rise_of_at_least, scan, Day01, Jump,
+        ReadError, Report, Run, SlidingWindow,
+    };
+
+    #[test]
+    fn test_part1() {
+        let input = Day01::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day01::part1(&input), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day01::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day01::part2(&input), 5);
+    }
+
+    #[test]
+    fn test_scan() {
+        let mut windows = [SlidingWindow::<u64>::new(1), SlidingWindow::new(3)];
+        scan(include_str!("test.input.txt").as_bytes(), &mut windows).unwrap();
+        assert_eq!(windows[0].increases(), 7);
+        assert_eq!(windows[0].report().windows, 10);
+        assert_eq!(windows[1].increases(), 5);
+        assert_eq!(windows[1].report().windows, 8);
+
+        let error = scan("199\r\n200\r\n2x0\r\n".as_bytes(), &mut windows).unwrap_err();
+        assert!(matches!(
+            error,
+            ReadError::Parse(error) if error == ParseError::new(3, 1, "number", "2x0")
+        ));
+    }
+
+    #[test]
+    fn test_reports() {
+        let input = Day01::parse(include_str!("test.input.txt")).unwrap();
+        let sweep = reports(input, &[1, 3]);
+        assert_eq!(
+            sweep[0],
+            Report {
+                window_size: 1,
+                windows: 10,
+                increases: 7,
+                decreases: 2,
+                plateaus: 0,
+                longest_increase: Some(Run { start: 0, end: 3 }),
+                longest_decrease: Some(Run { start: 3, end: 4 }),
+                largest_jump: Some(Jump {
+                    window: 6,
+                    from: 207,
+                    to: 240
+                }),
+            }
+        );
+        assert_eq!(
+            sweep[1],
+            Report {
+                window_size: 3,
+                windows: 8,
+                increases: 5,
+                decreases: 1,
+                plateaus: 1,
+                longest_increase: Some(Run { start: 3, end: 7 }),
+                longest_decrease: Some(Run { start: 2, end: 3 }),
+                largest_jump: Some(Jump {
+                    window: 5,
+                    from: 647,
+                    to: 716
+                }),
+            }
+        );
+
+        let flat = reports([5u64, 5], &[1, 2, 3]);
+        assert_eq!(
+            (flat[0].plateaus, flat[0].largest_jump.unwrap().distance()),
+            (1, 0)
+        );
+        assert_eq!((flat[1].windows, flat[1].largest_jump), (1, None));
+        assert_eq!(flat[2].windows, 0);
+    }
+
+    #[test]
+    fn test_generic_depths() {
+        let signed = parse_depths::<i32>("-3\n-1\n-4\n-4\n2").unwrap();
+        assert_eq!(count_increases(signed.iter().copied(), 1), 2);
+        assert_eq!(count_changes(signed, 1, rise_of_at_least(3)), 1);
+
+        let floats = parse_depths::<f64>("1.5\n1.25\n2\n2.5").unwrap();
+        assert_eq!(count_increases(floats.iter().copied(), 2), 2);
+        assert_eq!(count_changes(floats, 1, rise_of_at_least(0.6)), 1);
+
+        assert_eq!(
+            parse_depths::<f32>("1.5\nNaN"),
+            Err(ParseError::new(2, 1, "finite number", "NaN"))
+        );
+        assert_eq!(
+            parse_depths::<u8>("1\n  256"),
+            Err(ParseError::new(2, 3, "number", "256"))
+        );
+    }
+
+    #[test]
+    fn test_float_sums() {
+        let steady = reports([0.1f64, 0.2, 0.2, 0.2], &[1]);
+        assert_eq!((steady[0].increases, steady[0].plateaus), (1, 2));
+        assert_eq!(count_increases([0.1f64, 0.2, 0.2, 0.2], 1), 1);
+
+        let jump = reports([1e16f64, 1.0, 1.0, 2.0], &[1])[0];
+        assert_eq!(
+            jump.largest_jump,
+            Some(Jump {
+                window: 1,
+                from: 1e16,
+                to: 1.0
+            })
+        );
+        assert_eq!((jump.increases, jump.decreases, jump.plateaus), (1, 1, 1));
+        assert_eq!(
+            count_changes([1e16f64, 1.0, 1.0, 2.0], 1, rise_of_at_least(1.0)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_streaming() {
+        // Window sums well past u64::MAX, over ten million depths
+        let depths = (0..10_000_000u64).map(|i| (i % 7) * (u64::MAX / 8));
+        assert_eq!(count_increases(depths, 3), 5_714_284);
+        assert_eq!(count_increases(std::iter::empty::<u64>(), 3), 0);
+    }
+}
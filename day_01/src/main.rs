@@ -1,28 +1,25 @@
-fn main() {
-    let input = include_str!("./input.txt");
-    let numbers = input
-        .lines()
-        .map(|l| l.parse().unwrap())
-        .collect::<Vec<usize>>();
-
-    let part1 = solve(&numbers, 1);
-    assert_eq!(part1, 1557);
-    println!("{} measurements increased", part1);
+use common::{input, trace, Solution};
+use day_01::{Day01, SlidingWindow};
 
-    let part2 = solve(&numbers, 3);
-    assert_eq!(part2, 1608);
-    println!("{} measurements increased", part2);
-}
+fn main() {
+    trace::init();
+    let reader = input::open_or_exit(Day01::DAY, env!("CARGO_MANIFEST_DIR"));
 
-fn solve(input: &[usize], window_size: usize) -> usize {
-    let windows = input
-        .windows(window_size)
-        .map(|w| w.iter().sum())
-        .collect::<Vec<usize>>();
+    // Both parts in a single pass, so that even huge depth logs never sit in memory
+    let mut windows = [SlidingWindow::<u64>::new(1), SlidingWindow::new(3)];
+    if let Err(error) = day_01::scan(reader, &mut windows) {
+        eprintln!(
+            "Could not read the depths for day {}: {}",
+            Day01::DAY,
+            error
+        );
+        std::process::exit(1);
+    }
 
-    windows
-        .iter()
-        .zip(windows.iter().skip(1))
-        .filter(|(prev, next)| next > prev)
-        .count()
+    for window in &windows {
+        println!("{} measurements increased", window.increases());
+    }
+    for window in &windows {
+        println!("{}", window.report());
+    }
 }
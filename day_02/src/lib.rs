@@ -0,0 +1,109 @@
+use common::{checked::Overflow, parse::ParseError, Solution};
+
+pub mod script;
+pub mod simulator;
+
+pub use script::{Language, Script};
+use simulator::{Model, State, Submarine};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Script;
+    type Part1 = Result<i64, Overflow>;
+    type Part2 = Result<i64, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Language::standard().parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        solve(input, Model::Direct)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        solve(input, Model::Aimed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    Forward(usize),
+    Up(usize),
+    Down(usize),
+    /// Like `Forward`, but in reverse
+    Back(usize),
+    /// Swaps which way `Forward` and `Back` go
+    Turn,
+    /// Stays put for a number of steps
+    Wait(usize),
+}
+
+/// Final horizontal position times depth, which is negative once the submarine ends up above
+/// the surface or behind where it started.
+fn solve(script: &Script, model: Model) -> Result<i64, Overflow> {
+    let mut submarine = Submarine::new(model);
+    let mut motions = 0u64;
+    for motion in script.motions() {
+        submarine.apply(motion)?;
+        motions += 1;
+    }
+
+    let State {
+        horizontal, depth, ..
+    } = submarine.state();
+    tracing::debug!(motions, horizontal, depth);
+    horizontal
+        .checked_mul(depth)
+        .ok_or(Overflow("position product"))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, Solution};
+
+    use crate::Day02;
+
+    #[test]
+    fn test_part1() {
+        let input = Day02::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day02::part1(&input), Ok(150));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day02::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day02::part2(&input), Ok(900));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let input = Day02::parse(&format!("forward {}", usize::MAX)).unwrap();
+        assert_eq!(Day02::part1(&input), Err(Overflow("horizontal position")));
+
+        let input = Day02::parse(&format!("forward {}\ndown 2", i64::MAX)).unwrap();
+        assert_eq!(Day02::part1(&input), Err(Overflow("position product")));
+    }
+
+    #[test]
+    fn test_extended_commands() {
+        let input =
+            Day02::parse("down 2\nrepeat 3 { forward 4 }\nturn\nforward 5\nwait 9").unwrap();
+        assert_eq!(Day02::part1(&input), Ok(14));
+        assert_eq!(Day02::part2(&input), Ok(98));
+
+        let input = Day02::parse("down 1\nturn\nback 3\nturn\nback 3").unwrap();
+        assert_eq!(Day02::part2(&input), Ok(0));
+        assert_eq!(Day02::part1(&input), Ok(0));
+
+        let input = Day02::parse("forward 2\nup 1").unwrap();
+        assert_eq!(Day02::part1(&input), Ok(-2));
+        assert_eq!(Day02::part2(&input), Ok(0));
+
+        let input = Day02::parse("down 1\nback 3").unwrap();
+        assert_eq!(Day02::part1(&input), Ok(-3));
+        assert_eq!(Day02::part2(&input), Ok(9));
+    }
+}
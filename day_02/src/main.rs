@@ -1,60 +1,14 @@
-fn main() {
-    let input = include_str!("./input.txt");
-    let motions = input
-        .lines()
-        .map(|l| {
-            let mut parts = l.split(' ');
-            let motion = parts.next().unwrap();
-            let units = parts.next().unwrap().parse::<usize>().unwrap();
+use common::{input, trace, Part, Solution};
+use day_02::Day02;
 
-            match motion {
-                "forward" => Motion::Forward(units),
-                "up" => Motion::Up(units),
-                "down" => Motion::Down(units),
-                _ => unreachable!(),
-            }
-        })
-        .collect::<Vec<Motion>>();
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day02::DAY, env!("CARGO_MANIFEST_DIR"));
+    let script = common::parse_or_exit::<Day02>(&input);
 
-    let part1 = solve1(&motions);
-    assert_eq!(part1, 1714680);
+    let part1 = common::answer_or_exit::<Day02>(Part::One, trace::part1::<Day02>(&script));
     println!("{} final position product", part1);
 
-    let part2 = solve2(&motions);
-    // assert_eq!(part2, 1608);
+    let part2 = common::answer_or_exit::<Day02>(Part::Two, trace::part2::<Day02>(&script));
     println!("{} final position product", part2);
 }
-
-enum Motion {
-    Forward(usize),
-    Up(usize),
-    Down(usize),
-}
-
-fn solve1(motions: &[Motion]) -> usize {
-    // acc = (horizontal, depth)
-    let (horizontal, depth) =
-        motions
-            .iter()
-            .fold((0, 0), |(horizontal, depth), motion| match motion {
-                Motion::Forward(units) => (horizontal + units, depth),
-                Motion::Up(units) => (horizontal, depth - units),
-                Motion::Down(units) => (horizontal, depth + units),
-            });
-
-    horizontal * depth
-}
-
-fn solve2(motions: &[Motion]) -> usize {
-    // acc = (horizontal, depth, aim)
-    let (horizontal, depth, _) =
-        motions
-            .iter()
-            .fold((0, 0, 0), |(horizontal, depth, aim), motion| match motion {
-                Motion::Forward(units) => (horizontal + units, depth + aim * units, aim),
-                Motion::Up(units) => (horizontal, depth, aim - units),
-                Motion::Down(units) => (horizontal, depth, aim + units),
-            });
-
-    horizontal * depth
-}
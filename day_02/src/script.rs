@@ -0,0 +1,491 @@
+//! The submarine command language. A script is a sequence of commands, each a word followed by
+//! its numbers on the same line, with `repeat N { ... }` blocks and `#` comments:
+//!
+//! ```text
+//! down 2
+//! repeat 3 {
+//!     forward 5 up 1  # several commands may share a line
+//! }
+//! ```
+//!
+//! Which commands exist is up to the [`Language`] a script is parsed with. Repeat blocks may
+//! unroll to at most [`MAX_STEPS`] commands in all, so that no script runs forever, and nest at
+//! most [`MAX_NESTING`] deep.
+
+use common::parse::{self, ParseError};
+
+use crate::Motion;
+
+/// A command the language understands, with how many numbers follow it and which motion they
+/// make up.
+#[derive(Clone, Copy)]
+pub struct Command {
+    pub name: &'static str,
+    pub arguments: usize,
+    pub motion: fn(&[usize]) -> Motion,
+}
+
+/// The commands a script may use, on top of the built-in `repeat`. Each one stands for one of
+/// the [`Motion`]s the submarine knows, so commands can be renamed or given other arguments,
+/// but one that moves in a new way needs a new motion as well.
+#[derive(Clone)]
+pub struct Language {
+    commands: Vec<Command>,
+}
+
+const REPEAT: &str = "repeat";
+
+/// Most commands a script's repeat blocks may unroll to, counting every pass through a block
+/// as one as well. Commands outside of any block take their own line of input each and are
+/// not counted.
+pub const MAX_STEPS: usize = 1_000_000;
+
+/// Most repeat blocks a command may be nested in, as parsing recurses once per block
+pub const MAX_NESTING: usize = 64;
+
+impl Language {
+    /// Just `forward`, `up` and `down` from the puzzle.
+    pub fn puzzle() -> Self {
+        Self { commands: vec![] }
+            .define("forward", 1, |units| Motion::Forward(units[0]))
+            .define("up", 1, |units| Motion::Up(units[0]))
+            .define("down", 1, |units| Motion::Down(units[0]))
+    }
+
+    /// The puzzle's commands along with `back`, `turn` and `wait`.
+    pub fn standard() -> Self {
+        Self::puzzle()
+            .define("back", 1, |units| Motion::Back(units[0]))
+            .define("turn", 0, |_| Motion::Turn)
+            .define("wait", 1, |steps| Motion::Wait(steps[0]))
+    }
+
+    /// Adds a command taking `arguments` numbers, replacing any earlier one of the same name,
+    /// e.g. an alias or a shorthand for a motion with fixed units.
+    pub fn define(
+        mut self,
+        name: &'static str,
+        arguments: usize,
+        motion: fn(&[usize]) -> Motion,
+    ) -> Self {
+        assert_ne!(name, REPEAT, "repeat is built into the language");
+        let command = Command {
+            name,
+            arguments,
+            motion,
+        };
+        match self.commands.iter_mut().find(|c| c.name == name) {
+            Some(existing) => *existing = command,
+            None => self.commands.push(command),
+        }
+        self
+    }
+
+    pub fn command(&self, name: &str) -> Option<&Command> {
+        self.commands.iter().find(|c| c.name == name)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Script, ParseError> {
+        let mut parser = Parser {
+            language: self,
+            lines: input.lines().collect(),
+            tokens: tokenize(input),
+            next: 0,
+            nesting: 0,
+        };
+        let (statements, _) = parser.block(false)?;
+        Ok(Script { statements })
+    }
+
+    /// All command names for error messages, e.g. "forward, up, down or repeat".
+    fn expected(&self) -> String {
+        let names = self
+            .commands
+            .iter()
+            .map(|c| c.name)
+            .chain([REPEAT])
+            .collect::<Vec<_>>();
+        let (last, rest) = names.split_last().unwrap();
+        format!("{} or {}", rest.join(", "), last)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+    Motion(Motion),
+    Repeat(usize, Vec<Statement>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Script {
+    pub statements: Vec<Statement>,
+}
+
+impl Script {
+    /// Every motion in order. Repeat blocks are unrolled as they are reached, so they take no
+    /// memory.
+    pub fn motions(&self) -> Motions<'_> {
+        Motions {
+            stack: vec![Frame {
+                statements: &self.statements,
+                next: 0,
+                remaining: 1,
+            }],
+        }
+    }
+}
+
+pub struct Motions<'a> {
+    stack: Vec<Frame<'a>>,
+}
+
+/// A block being walked through, `remaining` times including the current one
+struct Frame<'a> {
+    statements: &'a [Statement],
+    next: usize,
+    remaining: usize,
+}
+
+impl<'a> Iterator for Motions<'a> {
+    type Item = Motion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(statement) = frame.statements.get(frame.next) else {
+                if frame.remaining > 1 {
+                    frame.remaining -= 1;
+                    frame.next = 0;
+                } else {
+                    self.stack.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+
+            match statement {
+                Statement::Motion(motion) => return Some(*motion),
+                Statement::Repeat(count, body) if *count > 0 && !body.is_empty() => {
+                    self.stack.push(Frame {
+                        statements: body,
+                        next: 0,
+                        remaining: *count,
+                    })
+                }
+                Statement::Repeat(..) => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Word,
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    /// 1-based like in [`ParseError`]
+    line: usize,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, expected, self.text)
+    }
+}
+
+/// Splits the input into braces and words, leaving out whitespace and comments.
+fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let code = line.split('#').next().unwrap();
+        let mut word: Option<(usize, usize)> = None;
+
+        for (column, (offset, c)) in code.char_indices().enumerate() {
+            let kind = match c {
+                '{' => Some(Kind::Open),
+                '}' => Some(Kind::Close),
+                c if c.is_whitespace() => None,
+                _ => {
+                    word.get_or_insert((offset, column));
+                    continue;
+                }
+            };
+
+            if let Some((start, start_column)) = word.take() {
+                tokens.push(Token {
+                    kind: Kind::Word,
+                    text: &code[start..offset],
+                    line: i + 1,
+                    column: start_column + 1,
+                });
+            }
+            if let Some(kind) = kind {
+                tokens.push(Token {
+                    kind,
+                    text: &code[offset..offset + 1],
+                    line: i + 1,
+                    column: column + 1,
+                });
+            }
+        }
+        if let Some((start, start_column)) = word {
+            tokens.push(Token {
+                kind: Kind::Word,
+                text: &code[start..],
+                line: i + 1,
+                column: start_column + 1,
+            });
+        }
+    }
+    tokens
+}
+
+fn too_many_steps() -> String {
+    format!("at most {} steps in all", MAX_STEPS)
+}
+
+struct Parser<'a, 'l> {
+    language: &'l Language,
+    lines: Vec<&'a str>,
+    tokens: Vec<Token<'a>>,
+    next: usize,
+    /// Repeat blocks around the next token
+    nesting: usize,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.next).copied()
+    }
+
+    fn advance(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.next += 1;
+        token
+    }
+
+    /// Statements up to the end of the input, or up to the closing `}` of a `nested` block,
+    /// along with the number of steps they take. At the top level only repeat blocks count
+    /// towards [`MAX_STEPS`].
+    fn block(&mut self, nested: bool) -> Result<(Vec<Statement>, usize), ParseError> {
+        let (mut statements, mut steps) = (vec![], 0usize);
+        loop {
+            match self.advance() {
+                None if !nested => return Ok((statements, steps)),
+                None => return Err(self.end_of_input("'}'")),
+                Some(token) if token.kind == Kind::Close && nested => {
+                    return Ok((statements, steps))
+                }
+                Some(token) if token.kind == Kind::Word => {
+                    let (statement, mut statement_steps) = self.statement(token)?;
+                    if !nested && matches!(statement, Statement::Motion(_)) {
+                        statement_steps = 0;
+                    }
+                    steps = steps
+                        .checked_add(statement_steps)
+                        .filter(|steps| *steps <= MAX_STEPS)
+                        .ok_or_else(|| token.error(too_many_steps()))?;
+                    statements.push(statement);
+                }
+                Some(token) => return Err(token.error(self.language.expected())),
+            }
+        }
+    }
+
+    fn statement(&mut self, word: Token<'a>) -> Result<(Statement, usize), ParseError> {
+        if word.text == REPEAT {
+            let count_token = self.peek();
+            let count = self.argument(word, "repeat count")?;
+            let (body, body_steps) = match self.advance() {
+                Some(token) if token.kind == Kind::Open && self.nesting == MAX_NESTING => {
+                    let expected = format!("at most {} nested repeat blocks", MAX_NESTING);
+                    return Err(token.error(expected));
+                }
+                Some(token) if token.kind == Kind::Open => {
+                    self.nesting += 1;
+                    let body = self.block(true)?;
+                    self.nesting -= 1;
+                    body
+                }
+                Some(token) => return Err(token.error("'{'")),
+                None => return Err(self.end_of_input("'{'")),
+            };
+
+            let steps = count
+                .checked_mul(body_steps)
+                .and_then(|steps| steps.checked_add(1))
+                .filter(|steps| *steps <= MAX_STEPS)
+                .ok_or_else(|| count_token.unwrap().error(too_many_steps()))?;
+            return Ok((Statement::Repeat(count, body), steps));
+        }
+
+        let command = *self
+            .language
+            .command(word.text)
+            .ok_or_else(|| word.error(self.language.expected()))?;
+        let arguments = (0..command.arguments)
+            .map(|_| self.argument(word, "units"))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((Statement::Motion((command.motion)(&arguments)), 1))
+    }
+
+    /// The next number, which has to be on the same line as `command`.
+    fn argument(&mut self, command: Token<'a>, expected: &str) -> Result<usize, ParseError> {
+        match self.peek() {
+            Some(token) if token.line == command.line && token.kind == Kind::Word => {
+                self.next += 1;
+                let line = self.lines[token.line - 1];
+                parse::number(token.line, line, token.text)
+            }
+            Some(token) if token.line == command.line => Err(token.error(expected)),
+            _ => Err(ParseError::end_of_line(
+                command.line,
+                self.lines[command.line - 1],
+                expected,
+            )),
+        }
+    }
+
+    fn end_of_input(&self, expected: &str) -> ParseError {
+        let line = self.lines.last().copied().unwrap_or("");
+        ParseError::end_of_line(self.lines.len().max(1), line, expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::{
+        script::{Language, Statement},
+        Motion,
+    };
+
+    #[test]
+    fn test_parse() {
+        let script = Language::standard()
+            .parse("down 2 # dive\nrepeat 2 {\n  forward 5 turn\n  repeat 0 {}\n}\nwait 3")
+            .unwrap();
+        assert_eq!(
+            script.statements,
+            vec![
+                Statement::Motion(Motion::Down(2)),
+                Statement::Repeat(
+                    2,
+                    vec![
+                        Statement::Motion(Motion::Forward(5)),
+                        Statement::Motion(Motion::Turn),
+                        Statement::Repeat(0, vec![]),
+                    ]
+                ),
+                Statement::Motion(Motion::Wait(3)),
+            ]
+        );
+        assert_eq!(
+            script.motions().collect::<Vec<_>>(),
+            vec![
+                Motion::Down(2),
+                Motion::Forward(5),
+                Motion::Turn,
+                Motion::Forward(5),
+                Motion::Turn,
+                Motion::Wait(3),
+            ]
+        );
+
+        let long = Language::puzzle()
+            .parse("repeat 1000 { repeat 332 { up 1 down 1 } }")
+            .unwrap();
+        assert_eq!(long.motions().count(), 664_000);
+
+        let plain = "forward 1\n".repeat(1_000_001);
+        let plain = Language::puzzle().parse(&plain).unwrap();
+        assert_eq!(plain.motions().count(), 1_000_001);
+
+        let after_repeat = Language::puzzle()
+            .parse("repeat 999999 { up 1 }\ndown 1\ndown 1")
+            .unwrap();
+        assert_eq!(after_repeat.motions().count(), 1_000_001);
+    }
+
+    #[test]
+    fn test_errors() {
+        let language = Language::standard();
+        let commands = "forward, up, down, back, turn, wait or repeat";
+        assert_eq!(
+            language.parse("forward 1\nsideways 2").unwrap_err(),
+            ParseError::new(2, 1, commands, "sideways")
+        );
+        assert_eq!(
+            language.parse("forward\n2").unwrap_err(),
+            ParseError::new(1, 8, "units", "")
+        );
+        assert_eq!(
+            language.parse("forward 1x").unwrap_err(),
+            ParseError::new(1, 9, "number", "1x")
+        );
+        assert_eq!(
+            language.parse("repeat 2 forward 1").unwrap_err(),
+            ParseError::new(1, 10, "'{'", "forward")
+        );
+        assert_eq!(
+            language.parse("repeat 2 {\n  up 1").unwrap_err(),
+            ParseError::new(2, 7, "'}'", "")
+        );
+        assert_eq!(
+            language.parse("up 1 }").unwrap_err(),
+            ParseError::new(1, 6, commands, "}")
+        );
+        assert_eq!(
+            language
+                .parse(&format!("repeat {} {{ turn }}", usize::MAX))
+                .unwrap_err(),
+            ParseError::new(1, 8, "at most 1000000 steps in all", usize::MAX.to_string())
+        );
+        assert_eq!(
+            language
+                .parse("repeat 1000000 { repeat 0 {} }")
+                .unwrap_err(),
+            ParseError::new(1, 8, "at most 1000000 steps in all", "1000000")
+        );
+        assert_eq!(
+            language
+                .parse("repeat 999999 { turn }\nrepeat 1 { turn }")
+                .unwrap_err(),
+            ParseError::new(2, 1, "at most 1000000 steps in all", "repeat")
+        );
+        let nested = |depth| "repeat 1 {".repeat(depth) + "turn" + &"}".repeat(depth);
+        assert_eq!(language.parse(&nested(64)).unwrap().motions().count(), 1);
+        assert_eq!(
+            language.parse(&nested(65)).unwrap_err(),
+            ParseError::new(1, 650, "at most 64 nested repeat blocks", "{")
+        );
+        assert_eq!(
+            language.parse(&nested(200_000)).unwrap_err(),
+            ParseError::new(1, 650, "at most 64 nested repeat blocks", "{")
+        );
+        assert_eq!(
+            Language::puzzle().parse("back 1").unwrap_err(),
+            ParseError::new(1, 1, "forward, up, down or repeat", "back")
+        );
+    }
+
+    #[test]
+    fn test_define() {
+        let language = Language::puzzle()
+            .define("dive", 2, |units| Motion::Down(units[0] * units[1]))
+            .define("up", 0, |_| Motion::Up(1));
+        let script = language.parse("dive 3 4 up").unwrap();
+        assert_eq!(
+            script.motions().collect::<Vec<_>>(),
+            vec![Motion::Down(12), Motion::Up(1)]
+        );
+    }
+}
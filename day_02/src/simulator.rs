@@ -0,0 +1,295 @@
+//! Follows the submarine motion by motion on signed coordinates, so it may surface above the
+//! water or go back past where it started, and records where it went for inspection.
+
+use std::{
+    convert::TryFrom,
+    io::{self, Write},
+};
+
+use common::checked::Overflow;
+
+use crate::{Motion, Script};
+
+const HORIZONTAL: &str = "horizontal position";
+const DEPTH: &str = "depth";
+const AIM: &str = "aim";
+const TIME: &str = "time";
+
+/// How `up` and `down` are read: part 1 changes the depth directly, part 2 the aim that going
+/// forward dives or climbs along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    Direct,
+    Aimed,
+}
+
+/// Where the submarine is after a motion. `time` counts one step per motion, except for `wait`
+/// taking as many steps as it says.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    pub time: u64,
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Submarine {
+    model: Model,
+    state: State,
+    turned: bool,
+}
+
+impl Submarine {
+    pub fn new(model: Model) -> Self {
+        Self {
+            model,
+            state: State::default(),
+            turned: false,
+        }
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Carries out `motion`, returning the new state. Going back with the aimed model climbs
+    /// back along the aim, retracing what going forward would have done.
+    pub fn apply(&mut self, motion: Motion) -> Result<State, Overflow> {
+        let state = &mut self.state;
+        let steps = match motion {
+            Motion::Wait(steps) => steps as u64,
+            _ => 1,
+        };
+        state.time = state.time.checked_add(steps).ok_or(Overflow(TIME))?;
+
+        let (vertical, what) = match self.model {
+            Model::Direct => (&mut state.depth, DEPTH),
+            Model::Aimed => (&mut state.aim, AIM),
+        };
+        match motion {
+            Motion::Forward(units) | Motion::Back(units) => {
+                let ahead = matches!(motion, Motion::Forward(_)) != self.turned;
+                let units = signed(units, HORIZONTAL)?;
+                let units = if ahead { units } else { -units };
+
+                state.horizontal = add(state.horizontal, units, HORIZONTAL)?;
+                if self.model == Model::Aimed {
+                    let descent = state.aim.checked_mul(units).ok_or(Overflow(DEPTH))?;
+                    state.depth = add(state.depth, descent, DEPTH)?;
+                }
+            }
+            Motion::Up(units) => *vertical = add(*vertical, -signed(units, what)?, what)?,
+            Motion::Down(units) => *vertical = add(*vertical, signed(units, what)?, what)?,
+            Motion::Turn => self.turned = !self.turned,
+            Motion::Wait(_) => {}
+        }
+        Ok(self.state)
+    }
+}
+
+fn signed(units: usize, what: &'static str) -> Result<i64, Overflow> {
+    i64::try_from(units).map_err(|_| Overflow(what))
+}
+
+fn add(a: i64, b: i64, what: &'static str) -> Result<i64, Overflow> {
+    a.checked_add(b).ok_or(Overflow(what))
+}
+
+/// Runs the whole script, recording the state before the first motion and after every one.
+pub fn simulate(script: &Script, model: Model) -> Result<Trajectory, Overflow> {
+    let mut submarine = Submarine::new(model);
+    let mut states = vec![submarine.state()];
+    for motion in script.motions() {
+        states.push(submarine.apply(motion)?);
+    }
+    Ok(Trajectory { states })
+}
+
+/// What a trajectory can be exported as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Export {
+    Csv,
+    Svg,
+}
+
+impl Export {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "csv" => Some(Export::Csv),
+            "svg" => Some(Export::Svg),
+            _ => None,
+        }
+    }
+}
+
+const SVG_WIDTH: f64 = 800.0;
+const SVG_HEIGHT: f64 = 400.0;
+const SVG_MARGIN: f64 = 20.0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    /// Never empty, starting at the surface
+    pub states: Vec<State>,
+}
+
+impl Trajectory {
+    pub fn last(&self) -> State {
+        *self.states.last().unwrap()
+    }
+
+    pub fn export(&self, format: Export, out: &mut dyn Write) -> io::Result<()> {
+        match format {
+            Export::Csv => self.write_csv(out),
+            Export::Svg => self.write_svg(out),
+        }
+    }
+
+    /// One row per state, below a `time,horizontal,depth,aim` header.
+    pub fn write_csv(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "time,horizontal,depth,aim")?;
+        for state in &self.states {
+            writeln!(
+                out,
+                "{},{},{},{}",
+                state.time, state.horizontal, state.depth, state.aim
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Side view of the path, horizontal position to the right and depth downwards, stretched
+    /// to fill the picture. The dashed line is the surface.
+    pub fn write_svg(&self, out: &mut dyn Write) -> io::Result<()> {
+        let range = |value: fn(&State) -> i64| {
+            let values = self.states.iter().map(value);
+            // The surface is always in view
+            let min = values.clone().min().unwrap().min(0);
+            let max = values.max().unwrap().max(0);
+            (min, max)
+        };
+        let (left, right) = range(|state| state.horizontal);
+        let (top, bottom) = range(|state| state.depth);
+
+        let scale = |value: i64, min: i64, max: i64, size: f64| {
+            let span = (max as f64 - min as f64).max(1.0);
+            SVG_MARGIN + (value as f64 - min as f64) / span * (size - 2.0 * SVG_MARGIN)
+        };
+        let x = |horizontal| scale(horizontal, left, right, SVG_WIDTH);
+        let y = |depth| scale(depth, top, bottom, SVG_HEIGHT);
+
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            SVG_WIDTH, SVG_HEIGHT
+        )?;
+        writeln!(
+            out,
+            "<title>horizontal {} to {}, depth {} to {}</title>",
+            left, right, top, bottom
+        )?;
+        writeln!(out, r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+        writeln!(
+            out,
+            r#"<line x1="0" y1="{0:.1}" x2="{1}" y2="{0:.1}" stroke="steelblue" stroke-dasharray="4"/>"#,
+            y(0),
+            SVG_WIDTH
+        )?;
+
+        write!(out, r#"<polyline fill="none" stroke="black" points=""#)?;
+        for (i, state) in self.states.iter().enumerate() {
+            let separator = if i == 0 { "" } else { " " };
+            write!(
+                out,
+                "{}{:.1},{:.1}",
+                separator,
+                x(state.horizontal),
+                y(state.depth)
+            )?;
+        }
+        writeln!(out, r#""/>"#)?;
+
+        let last = self.last();
+        writeln!(
+            out,
+            r#"<circle cx="{:.1}" cy="{:.1}" r="4" fill="crimson"/>"#,
+            x(last.horizontal),
+            y(last.depth)
+        )?;
+        writeln!(out, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::checked::Overflow;
+
+    use crate::{
+        simulator::{simulate, Export, Model, State},
+        Language,
+    };
+
+    #[test]
+    fn test_simulate() {
+        let script = Language::standard()
+            .parse("down 2\nforward 3\nwait 5\nturn\nforward 1\nup 4")
+            .unwrap();
+
+        let direct = simulate(&script, Model::Direct).unwrap();
+        assert_eq!(direct.states.len(), 7);
+        assert_eq!(
+            direct.last(),
+            State {
+                time: 10,
+                horizontal: 2,
+                depth: -2,
+                aim: 0
+            }
+        );
+
+        let aimed = simulate(&script, Model::Aimed).unwrap();
+        assert_eq!(
+            aimed.states[2],
+            State {
+                time: 2,
+                horizontal: 3,
+                depth: 6,
+                aim: 2
+            }
+        );
+        assert_eq!(
+            aimed.last(),
+            State {
+                time: 10,
+                horizontal: 2,
+                depth: 4,
+                aim: -2
+            }
+        );
+
+        let script = Language::standard()
+            .parse(&format!("wait {}\nwait 1", u64::MAX))
+            .unwrap();
+        assert_eq!(simulate(&script, Model::Direct), Err(Overflow("time")));
+    }
+
+    #[test]
+    fn test_export() {
+        let script = Language::standard().parse("forward 2\nup 1").unwrap();
+        let trajectory = simulate(&script, Model::Direct).unwrap();
+
+        let mut csv = vec![];
+        trajectory.export(Export::Csv, &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "time,horizontal,depth,aim\n0,0,0,0\n1,2,0,0\n2,2,-1,0\n"
+        );
+
+        let mut svg = vec![];
+        trajectory.export(Export::Svg, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r#"points="20.0,380.0 780.0,380.0 780.0,20.0""#));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,174 @@
+use std::iter::FromIterator;
+
+use common::{parse::ParseError, NoAnswer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = (Vec<u16>, u32);
+    type Part1 = usize;
+    type Part2 = Result<usize, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let bits = input.lines().next().map(|l| l.trim().len()).unwrap_or(0);
+        Ok((parse_input(input)?, bits as u32))
+    }
+
+    fn part1((numbers, bits): &Self::Input) -> Self::Part1 {
+        let (gamma, epsilon) = find_gamma_and_epsilon(numbers, *bits);
+        gamma * epsilon
+    }
+
+    fn part2((numbers, bits): &Self::Input) -> Self::Part2 {
+        solve2(numbers, *bits).ok_or(NoAnswer("the bit criteria do not single out a rating"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<u16>, ParseError> {
+    let width = input.lines().next().map(|l| l.trim().len()).unwrap_or(0);
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let number = line.trim();
+            if number.is_empty() {
+                return Err(ParseError::end_of_line(i + 1, line, "binary number"));
+            }
+            if let Some((column, c)) = number.char_indices().find(|(_, c)| !matches!(c, '0' | '1'))
+            {
+                return Err(ParseError::at(
+                    i + 1,
+                    line,
+                    &number[column..column + c.len_utf8()],
+                    "0 or 1",
+                ));
+            }
+            if number.len() < width {
+                return Err(ParseError::end_of_line(
+                    i + 1,
+                    line,
+                    format!("{} bits", width),
+                ));
+            }
+            if number.len() > width.min(16) {
+                let end = width.min(16);
+                return Err(ParseError::at(i + 1, line, &number[end..], "end of line"));
+            }
+            Ok(u16::from_str_radix(number, 2).unwrap())
+        })
+        .collect()
+}
+
+fn find_gamma_and_epsilon(numbers: &[u16], bits: u32) -> (usize, usize) {
+    let gamma = (0..bits).fold(0u32, |g, offset| {
+        let n_ones = count_ones_at_offset(numbers, bits - offset - 1);
+        let n_zeroes = numbers.len() - n_ones;
+
+        (g << 1) | (n_ones > n_zeroes) as u32
+    });
+
+    // Numbers have at most 16 bits, so this cannot overflow a u32
+    let epsilon = (1 << bits) - 1 - gamma;
+
+    (gamma as usize, epsilon as usize)
+}
+
+fn count_ones_at_offset(numbers: &[u16], digit: u32) -> usize {
+    let mask = 1 << digit;
+    numbers.iter().filter(|n| (*n & mask).eq(&mask)).count()
+}
+
+fn solve2(lines: &[u16], bits: u32) -> Option<usize> {
+    let oxygen_generator_rating = find_rating(lines, true, bits)?;
+    let co2_scrubber_rating = find_rating(lines, false, bits)?;
+
+    Some(oxygen_generator_rating * co2_scrubber_rating)
+}
+
+/// Filters the numbers bit by bit until one is left, or `None` if none or several remain.
+fn find_rating(numbers: &[u16], most_common_bit: bool, bits: u32) -> Option<usize> {
+    let mut numbers = Vec::from_iter(numbers.to_owned());
+    let mut rounds = 0;
+
+    for offset in (0..bits).rev() {
+        if numbers.len() == 1 {
+            break;
+        }
+        rounds += 1;
+
+        let n_ones = count_ones_at_offset(&numbers, offset);
+        let n_zeroes = numbers.len() - n_ones;
+
+        let expected_value = {
+            if most_common_bit {
+                if n_ones >= n_zeroes {
+                    1
+                } else {
+                    0
+                }
+            } else if n_zeroes <= n_ones {
+                0
+            } else {
+                1
+            }
+        };
+
+        let mask = 1 << offset;
+        numbers.retain(|n| ((n & mask) >> offset).eq(&expected_value));
+    }
+
+    if numbers.len() == 1 {
+        tracing::debug!(most_common_bit, rounds, "rating found");
+        return Some(numbers[0] as usize);
+    }
+    tracing::debug!(most_common_bit, left = numbers.len(), "no rating");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse::ParseError, NoAnswer, Solution};
+
+    use crate::{find_gamma_and_epsilon, parse_input, solve2, Day03};
+
+    #[test]
+    fn test_solve1() {
+        let numbers = parse_input(include_str!("./test.input.txt")).unwrap();
+        let (gamma, epsilon) = find_gamma_and_epsilon(&numbers, 5);
+        assert_eq!(gamma * epsilon, 198);
+    }
+
+    #[test]
+    fn test_solve2() {
+        let numbers = parse_input(include_str!("./test.input.txt")).unwrap();
+        assert_eq!(solve2(&numbers, 5), Some(230));
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let input = Day03::parse("1111111111111111").unwrap();
+        assert_eq!(Day03::part1(&input), 0);
+        assert_eq!(Day03::part2(&input), Ok(65535 * 65535));
+        assert_eq!(Day03::part2(&Day03::parse("01").unwrap()), Ok(1));
+
+        let no_rating = Err(NoAnswer("the bit criteria do not single out a rating"));
+        for input in ["", "01\n01"] {
+            assert_eq!(Day03::part2(&Day03::parse(input).unwrap()), no_rating);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("00100\n11120").err();
+        assert_eq!(error, Some(ParseError::new(2, 4, "0 or 1", "2")));
+
+        let error = parse_input("00100\n1111").err();
+        assert_eq!(error, Some(ParseError::new(2, 5, "5 bits", "")));
+
+        let error = parse_input("00100\n111100").err();
+        assert_eq!(error, Some(ParseError::new(2, 6, "end of line", "0")));
+    }
+}
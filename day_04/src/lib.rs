@@ -0,0 +1,251 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use common::{
+    checked::Overflow,
+    parse::{self, ParseError},
+    NoAnswer, Solution, SolveError,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = (Vec<u32>, Vec<Board>);
+    type Part1 = Result<u32, SolveError>;
+    type Part2 = Result<u32, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((numbers, boards): &Self::Input) -> Self::Part1 {
+        let scores = solve(numbers, boards.clone());
+        Ok((*scores.first().ok_or(NO_WINNER)?)?)
+    }
+
+    fn part2((numbers, boards): &Self::Input) -> Self::Part2 {
+        let scores = solve(numbers, boards.clone());
+        Ok((*scores.last().ok_or(NO_WINNER)?)?)
+    }
+}
+
+const NO_WINNER: NoAnswer = NoAnswer("no board wins");
+
+/// Final scores of the boards in the order they win.
+fn solve(numbers: &[u32], mut boards: Vec<Board>) -> Vec<Result<u32, Overflow>> {
+    let mut winners = Vec::new();
+
+    let mut draws = 0;
+    for &n in numbers {
+        draws += 1;
+        let mut to_remove = Vec::new();
+
+        for (i, b) in boards.iter_mut().enumerate() {
+            b.mark(n);
+            if b.is_winner() {
+                let score = b
+                    .score()
+                    .and_then(|score| score.checked_mul(n).ok_or(Overflow("final score")));
+                winners.push(score);
+                to_remove.push(i);
+            }
+        }
+
+        while let Some(i) = to_remove.pop() {
+            boards.remove(i);
+        }
+    }
+
+    tracing::debug!(draws, winners = winners.len());
+    winners
+}
+
+fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<Board>), ParseError> {
+    let blocks = parse::blocks(input);
+    let (start, raw_numbers) = blocks
+        .first()
+        .ok_or_else(|| ParseError::new(1, 1, "drawn numbers", ""))?;
+
+    // The drawn numbers may wrap onto further lines after a comma
+    let lines = raw_numbers.lines().count();
+    let numbers = raw_numbers
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            let numbers = match line.trim_end().strip_suffix(',') {
+                Some(wrapped) if i + 1 < lines => wrapped,
+                _ => line,
+            };
+            numbers
+                .split(',')
+                .map(move |x| parse::number(start + i + 1, line, x.trim()))
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let boards = blocks[1..]
+        .iter()
+        .map(|(start, b)| Board::from_str(b).map_err(|error| error.shift_lines(*start)))
+        .collect::<Result<Vec<Board>, _>>()?;
+
+    Ok((numbers, boards))
+}
+
+#[derive(Clone)]
+pub struct Board {
+    numbers: HashMap<u32, (usize, usize)>,
+    positions: HashMap<(usize, usize), u32>,
+    marks: HashMap<(usize, usize), bool>,
+}
+
+impl Board {
+    fn mark(&mut self, number: u32) {
+        if let Some((row, col)) = self.numbers.get(&number) {
+            self.marks.insert((*row, *col), true);
+        }
+    }
+
+    fn is_winner(&self) -> bool {
+        (0..5).any(|row| (0..5).all(move |col| *self.marks.get(&(row, col)).unwrap()))
+            || (0..5).any(|col| (0..5).all(move |row| *self.marks.get(&(row, col)).unwrap()))
+    }
+
+    fn score(&self) -> Result<u32, Overflow> {
+        self.marks
+            .iter()
+            .filter(|(_, mark)| !**mark)
+            .map(|(pos, _)| self.positions.get(pos).unwrap())
+            .try_fold(0u32, |sum, n| sum.checked_add(*n))
+            .ok_or(Overflow("sum of unmarked numbers"))
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut positions = HashMap::default();
+        let mut numbers = HashMap::default();
+        let mut rows = 0;
+
+        for (row, line) in s.lines().enumerate() {
+            if row == 5 {
+                return Err(ParseError::at(row + 1, line, line, "end of board"));
+            }
+
+            let mut cols = 0;
+            for (col, n) in line.split_whitespace().enumerate() {
+                if col == 5 {
+                    return Err(ParseError::at(row + 1, line, n, "end of row"));
+                }
+
+                let n = parse::number::<u32>(row + 1, line, n)?;
+                positions.insert((row, col), n);
+                numbers.insert(n, (row, col));
+                cols += 1;
+            }
+
+            if cols < 5 {
+                return Err(ParseError::end_of_line(row + 1, line, "5 numbers per row"));
+            }
+            rows += 1;
+        }
+
+        if rows < 5 {
+            return Err(ParseError::new(rows + 1, 1, "5 rows per board", ""));
+        }
+
+        let marks = (0..5)
+            .flat_map(|row| (0..5).map(move |col| ((row, col), false)))
+            .collect();
+
+        Ok(Board {
+            numbers,
+            marks,
+            positions,
+        })
+    }
+}
+
+impl Debug for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..5 {
+            for col in 0..5 {
+                if *self.marks.get(&(row, col)).unwrap() {
+                    write!(
+                        f,
+                        "\x1b[0;32m{:02?} \x1b[0m",
+                        self.positions.get(&(row, col)).unwrap()
+                    )
+                    .unwrap();
+                } else {
+                    write!(f, "{:02?} ", self.positions.get(&(row, col)).unwrap()).unwrap();
+                }
+            }
+            writeln!(f).unwrap();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, parse::ParseError, NoAnswer, Solution, SolveError};
+
+    use crate::{parse_input, Day04};
+
+    #[test]
+    fn test() {
+        let input = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day04::part1(&input), Ok(4512));
+        assert_eq!(Day04::part2(&input), Ok(1924));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let input = parse_input("0").unwrap();
+        let no_winner = Err(SolveError::NoAnswer(NoAnswer("no board wins")));
+        assert_eq!(Day04::part1(&input), no_winner);
+        assert_eq!(Day04::part2(&input), no_winner);
+
+        let board = "1 2 3 4 5\n6 7 8 9 4000000000\n10 11 12 13 4000000001\n\
+                     14 15 16 17 4000000002\n18 19 20 21 4000000003";
+        let input = parse_input(&format!("1,2,3,4,5\n\n{}", board)).unwrap();
+        assert_eq!(
+            Day04::part1(&input),
+            Err(SolveError::Overflow(Overflow("sum of unmarked numbers")))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("7,4,x\n\n22 13 17 11  0").err().unwrap();
+        assert_eq!(error, ParseError::new(1, 5, "number", "x"));
+
+        let (numbers, _) = parse_input("\n7,4,\n9,5").unwrap();
+        assert_eq!(numbers, vec![7, 4, 9, 5]);
+
+        let error = parse_input("\n\n7,4,\n9,x\n\n22 13 17 11  0")
+            .err()
+            .unwrap();
+        assert_eq!(error, ParseError::new(4, 3, "number", "x"));
+
+        let error = parse_input("7,4,\n9,\n\n22 13 17 11  0").err().unwrap();
+        assert_eq!(error, ParseError::new(2, 3, "number", ""));
+
+        let board = "22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16\n 6 10  3 18  5\n 1 12 20 15 19";
+        let error = parse_input(&format!("7,4\n\n{}", board)).err().unwrap();
+        assert_eq!(error, ParseError::new(5, 12, "5 numbers per row", ""));
+    }
+}
@@ -0,0 +1,235 @@
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        solve(lines, &|line: &Line| {
+            line.is_horizontal() || line.is_vertical()
+        })
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        solve(lines, &|_| true)
+    }
+}
+
+fn solve(lines: &[Line], selector: &dyn Fn(&Line) -> bool) -> usize {
+    let point_count = lines
+        .iter()
+        .filter(|l| selector(l))
+        .flat_map(|l| l.points())
+        .fold(HashMap::new(), |mut acc, point| {
+            *acc.entry(point).or_insert(0) += 1;
+            acc
+        });
+
+    let overlaps = point_count.iter().filter(|(_, count)| **count > 1).count();
+    tracing::debug!(points = point_count.len(), overlaps);
+    overlaps
+}
+
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| Line::from_str(line).map_err(|error| error.shift_lines(i)))
+        .collect()
+}
+
+/// Most points a line may cover, as every one of them gets counted
+const MAX_LENGTH: u32 = 10_000;
+
+pub struct Line {
+    start: (u32, u32),
+    end: (u32, u32),
+}
+
+impl Line {
+    fn is_horizontal(&self) -> bool {
+        self.start.0 == self.end.0
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.start.1 == self.end.1
+    }
+
+    /// Every point on the line, from `start` to `end`.
+    fn points(&self) -> impl Iterator<Item = (u32, u32)> {
+        let (start, end) = (self.start, self.end);
+        let length = start.0.abs_diff(end.0).max(start.1.abs_diff(end.1));
+        (0..=length).map(move |i| (towards(start.0, end.0, i), towards(start.1, end.1, i)))
+    }
+}
+
+/// `from` moved `steps` closer to `to`, or left alone if they are equal
+fn towards(from: u32, to: u32, steps: u32) -> u32 {
+    match from.cmp(&to) {
+        Ordering::Less => from + steps,
+        Ordering::Equal => from,
+        Ordering::Greater => from - steps,
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::end_of_line(1, s, "' -> '"))?;
+
+        let start = parse_point(s, start.trim())?;
+        let end = parse_point(s, end.trim())?;
+
+        let (start, end) = sort_tuples(start, end);
+
+        let (dx, dy) = (start.0.abs_diff(end.0), start.1.abs_diff(end.1));
+        if dx != 0 && dy != 0 && dx != dy {
+            let expected = "horizontal, vertical or diagonal line";
+            return Err(ParseError::at(1, s, s.trim(), expected));
+        }
+        if dx.max(dy) >= MAX_LENGTH {
+            let expected = format!("line of at most {} points", MAX_LENGTH);
+            return Err(ParseError::at(1, s, s.trim(), expected));
+        }
+
+        Ok(Self { start, end })
+    }
+}
+
+fn parse_point(line: &str, point: &str) -> Result<(u32, u32), ParseError> {
+    let (x, y) = point
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(1, line, point, "x,y"))?;
+
+    Ok((parse::number(1, line, x)?, parse::number(1, line, y)?))
+}
+
+/// Orders the endpoints by `(x, y)`, so horizontal and vertical lines run towards larger
+/// coordinates.
+fn sort_tuples(t1: (u32, u32), t2: (u32, u32)) -> ((u32, u32), (u32, u32)) {
+    if t2 >= t1 {
+        (t1, t2)
+    } else {
+        (t2, t1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use common::parse::ParseError;
+    use proptest::prelude::*;
+
+    use crate::{parse_input, solve, Line};
+
+    #[test]
+    fn test() {
+        let input = include_str!("test.input.txt");
+        let lines = parse_input(input).unwrap();
+        let part1 = solve(&lines, &|line| line.is_horizontal() || line.is_vertical());
+        assert_eq!(part1, 5);
+        let part2 = solve(&lines, &|_| true);
+        assert_eq!(part2, 12);
+    }
+
+    #[test]
+    fn test_points() {
+        let line = Line {
+            start: (5, 5),
+            end: (8, 2),
+        };
+        assert_eq!(
+            line.points().collect::<Vec<_>>(),
+            vec![(5, 5), (6, 4), (7, 3), (8, 2)]
+        );
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let line = Line::from_str("70000,1 -> 60001,1").unwrap();
+        assert_eq!((line.start, line.end), ((60001, 1), (70000, 1)));
+        assert_eq!(line.points().count(), 10000);
+
+        let line = Line::from_str("7,4294967295 -> 7,4294967290").unwrap();
+        assert_eq!((line.start, line.end), ((7, 4294967290), (7, 4294967295)));
+        assert_eq!(line.points().count(), 6);
+
+        let line = Line::from_str("4294967295,0 -> 4294967290,5").unwrap();
+        assert_eq!(line.points().last(), Some((4294967295, 0)));
+        assert_eq!(line.points().count(), 6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("0,9 -> 5,9\n8,0 -> 0,x").err().unwrap();
+        assert_eq!(error, ParseError::new(2, 10, "number", "x"));
+
+        let error = parse_input("0,9 -> 5,9\n8,0 0,8").err().unwrap();
+        assert_eq!(error, ParseError::new(2, 8, "' -> '", ""));
+
+        let error = parse_input("0,9 -> 59").err().unwrap();
+        assert_eq!(error, ParseError::new(1, 8, "x,y", "59"));
+
+        let error = parse_input("0,9 -> 5,9\n0,0 -> 2,1").err().unwrap();
+        let expected = "horizontal, vertical or diagonal line";
+        assert_eq!(error, ParseError::new(2, 1, expected, "0,0 -> 2,1"));
+
+        let error = parse_input("0,0 -> 0,4294967295").err().unwrap();
+        let expected = "line of at most 10000 points";
+        assert_eq!(
+            error,
+            ParseError::new(1, 1, expected, "0,0 -> 0,4294967295")
+        );
+    }
+
+    /// Endpoints of a horizontal, vertical or 45 degree diagonal line.
+    fn endpoints() -> impl Strategy<Value = ((u32, u32), (u32, u32))> {
+        (0..1000u32, 0..1000u32, 0..1000u32, 0..3u8).prop_flat_map(
+            |(x, y, length, kind)| match kind {
+                0 => Just(((x, y), (length, y))).boxed(),
+                1 => Just(((x, y), (x, length))).boxed(),
+                _ => {
+                    let length = length.min(x).min(999 - y);
+                    prop_oneof![
+                        Just(((x, y), (x - length, y + length))),
+                        Just(((x - length, y), (x, y + length))),
+                    ]
+                    .boxed()
+                }
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_point_count((start, end) in endpoints()) {
+            let raw = format!("{},{} -> {},{}", start.0, start.1, end.0, end.1);
+            let line = Line::from_str(&raw).unwrap();
+            let points = line.points().collect::<Vec<_>>();
+
+            let dx = start.0.abs_diff(end.0);
+            let dy = start.1.abs_diff(end.1);
+            prop_assert_eq!(points.len() as u32, dx.max(dy) + 1);
+            prop_assert!(points.contains(&start));
+            prop_assert!(points.contains(&end));
+        }
+    }
+}
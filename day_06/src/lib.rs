@@ -0,0 +1,99 @@
+use common::{
+    checked::{Count, Overflow},
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<usize>;
+    type Part1 = Result<Fish, Overflow>;
+    type Part2 = Result<Fish, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.trim();
+        line.split(',')
+            .map(|s| match parse::number(1, line, s.trim())? {
+                timer @ 0..=8 => Ok(timer),
+                _ => Err(ParseError::at(1, line, s.trim(), "timer 0-8")),
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input) -> Self::Part1 {
+        solve(numbers, 80)
+    }
+
+    fn part2(numbers: &Self::Input) -> Self::Part2 {
+        solve(numbers, 256)
+    }
+}
+
+/// The school roughly doubles every week, so `u64` runs out after about 440 days.
+#[cfg(not(feature = "bigint"))]
+pub type Fish = u64;
+#[cfg(feature = "bigint")]
+pub type Fish = common::checked::Big;
+
+fn solve<C: Count>(numbers: &[usize], iterations: usize) -> Result<C, Overflow> {
+    let add = |a: &C, b: &C| a.checked_add(b).ok_or(Overflow("fish count"));
+    let mut arr: [C; 9] = std::array::from_fn(|_| C::from(0));
+
+    for n in numbers {
+        arr[*n] = add(&arr[*n], &C::from(1))?;
+    }
+
+    for _ in 0..iterations {
+        for idx in 0..arr.len() - 1 {
+            arr.swap(idx, idx + 1);
+        }
+
+        arr[6] = add(&arr[6], &arr[8])?;
+    }
+
+    let fish = arr.iter().try_fold(C::from(0), |sum, n| add(&sum, n))?;
+    tracing::debug!(iterations, %fish);
+    Ok(fish)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, Answer, IntoAnswer, Solution};
+
+    use crate::{solve, Day06};
+
+    #[test]
+    fn test_part1() {
+        let input = Day06::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day06::part1(&input).into_answer(), Ok(Answer::Number(5934)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day06::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(
+            Day06::part2(&input).into_answer(),
+            Ok(Answer::Number(26984457539))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day06::parse(include_str!("test.input.txt")).unwrap();
+        assert!(solve::<u64>(&input, 400).is_ok());
+        assert_eq!(solve::<u64>(&input, 500), Err(Overflow("fish count")));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint() {
+        use common::checked::Big;
+
+        let input = Day06::parse(include_str!("test.input.txt")).unwrap();
+        let fish = solve::<Big>(&input, 500).unwrap();
+        assert!(fish > Big::from(u64::MAX));
+    }
+}
@@ -1,31 +1,14 @@
+use common::{input, trace, Part, Solution};
+use day_06::Day06;
+
 fn main() {
-    let input = include_str!("input.txt");
-    let numbers = input
-        .split(',')
-        .map(|s| s.parse::<usize>().unwrap())
-        .collect::<Vec<_>>();
+    trace::init();
+    let input = input::load_or_exit(Day06::DAY, env!("CARGO_MANIFEST_DIR"));
+    let numbers = common::parse_or_exit::<Day06>(&input);
 
-    let part1 = solve(&numbers, 80);
+    let part1 = common::answer_or_exit::<Day06>(Part::One, trace::part1::<Day06>(&numbers));
     println!("# Lanternfish after 80 days: {}", part1);
 
-    let part2 = solve(&numbers, 256);
+    let part2 = common::answer_or_exit::<Day06>(Part::Two, trace::part2::<Day06>(&numbers));
     println!("# Lanternfish after 256 days: {}", part2);
 }
-
-fn solve(numbers: &[usize], iterations: usize) -> usize {
-    let mut arr = [0usize; 9];
-
-    for n in numbers {
-        arr[*n] += 1;
-    }
-
-    for _ in 0..iterations {
-        for idx in 0..arr.len() - 1 {
-            arr.swap(idx, idx + 1);
-        }
-
-        arr[6] += arr[8];
-    }
-
-    arr.iter().sum()
-}
@@ -0,0 +1,97 @@
+use common::{
+    checked::Overflow,
+    parse::{self, ParseError},
+    Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i32>;
+    type Part1 = Result<i32, Overflow>;
+    type Part2 = Result<i32, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.trim();
+        line.split(',')
+            .map(|s| parse::number(1, line, s.trim()))
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input) -> Self::Part1 {
+        part1(numbers)
+    }
+
+    fn part2(numbers: &Self::Input) -> Self::Part2 {
+        part2(numbers)
+    }
+}
+
+fn part1(numbers: &[i32]) -> Result<i32, Overflow> {
+    min_fuel(numbers, Some)
+}
+
+fn part2(numbers: &[i32]) -> Result<i32, Overflow> {
+    min_fuel(numbers, |distance| {
+        Some(distance.checked_mul(distance.checked_add(1)?)? / 2)
+    })
+}
+
+/// Least fuel needed to line up all crabs, trying every position between the outermost ones.
+/// Positions whose total does not fit an `i32` cost more than any other, so they only make
+/// this fail when there is no cheaper one.
+fn min_fuel<F>(numbers: &[i32], fuel: F) -> Result<i32, Overflow>
+where
+    F: Fn(i32) -> Option<i32> + Sync,
+{
+    let min = *numbers.iter().min().unwrap();
+    let max = *numbers.iter().max().unwrap();
+    tracing::debug!(
+        crabs = numbers.len(),
+        positions = max as i64 - min as i64 + 1
+    );
+
+    let total = |pos: i32| {
+        numbers.iter().try_fold(0i32, |total, n| {
+            let distance = n.checked_sub(pos)?.checked_abs()?;
+            total.checked_add(fuel(distance)?)
+        })
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    let positions = min..=max;
+    #[cfg(feature = "parallel")]
+    let positions = (min..=max).into_par_iter();
+
+    positions.filter_map(total).min().ok_or(Overflow("fuel"))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, Solution};
+
+    use crate::Day07;
+
+    #[test]
+    fn test_part1() {
+        let input = Day07::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day07::part1(&input), Ok(37));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day07::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day07::part2(&input), Ok(168));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day07::parse("0,100000").unwrap();
+        assert_eq!(Day07::part1(&input), Ok(100000));
+        assert_eq!(Day07::part2(&input), Err(Overflow("fuel")));
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{parse::ParseError, NoAnswer, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = Result<usize, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        part1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        part2(lines)
+    }
+}
+
+fn part1(lines: &[Line]) -> usize {
+    let unique = lines
+        .iter()
+        .map(|l| {
+            l.outputs
+                .iter()
+                .filter(|o| o.len() == 2 || o.len() == 4 || o.len() == 3 || o.len() == 7)
+                .count()
+        })
+        .sum();
+
+    tracing::debug!(displays = lines.len(), unique);
+    unique
+}
+
+fn part2(lines: &[Line]) -> Result<usize, NoAnswer> {
+    tracing::debug!(displays = lines.len());
+    lines.iter().map(|l| l.clone().solve()).sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| Line::from_str(line).map_err(|error| error.shift_lines(i)))
+        .collect::<Result<Vec<_>, _>>()
+}
+
+const UNSOLVABLE: NoAnswer = NoAnswer("the patterns do not map onto the ten digits");
+
+#[derive(Clone)]
+pub struct Line {
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl Line {
+    fn solve(&mut self) -> Result<usize, NoAnswer> {
+        self.inputs.sort_by_key(|a| a.len());
+
+        let one = self.inputs.first().ok_or(UNSOLVABLE)?;
+        let seven = self.inputs.get(1).ok_or(UNSOLVABLE)?;
+        let four = self.inputs.get(2).ok_or(UNSOLVABLE)?;
+        let eight = self.inputs.get(9).ok_or(UNSOLVABLE)?;
+
+        let fives = self
+            .inputs
+            .iter()
+            .filter(|i| i.len() == 5)
+            .collect::<Vec<_>>();
+
+        let sixes = self
+            .inputs
+            .iter()
+            .filter(|i| i.len() == 6)
+            .collect::<Vec<_>>();
+
+        let three = fives
+            .iter()
+            .find(|s| one.chars().all(|c| s.contains(c)))
+            .ok_or(UNSOLVABLE)?;
+
+        let nine = sixes
+            .iter()
+            .find(|s| seven.chars().chain(four.chars()).all(|c| s.contains(c)))
+            .ok_or(UNSOLVABLE)?;
+
+        let six = sixes
+            .iter()
+            .find(|s| s.ne(&nine) && !one.chars().all(|c| s.contains(c)))
+            .ok_or(UNSOLVABLE)?;
+
+        let zero = sixes
+            .iter()
+            .find(|s| s.ne(&nine) && s.ne(&six))
+            .ok_or(UNSOLVABLE)?;
+
+        let five = fives
+            .iter()
+            .find(|s| left_str_minus_right_str(six, s).len() == 1)
+            .ok_or(UNSOLVABLE)?;
+
+        let two = fives
+            .iter()
+            .find(|s| s.ne(&three) && s.ne(&five))
+            .ok_or(UNSOLVABLE)?;
+
+        let mut mapping: HashMap<&String, usize> = HashMap::new();
+        mapping.insert(zero, 0);
+        mapping.insert(one, 1);
+        mapping.insert(two, 2);
+        mapping.insert(three, 3);
+        mapping.insert(four, 4);
+        mapping.insert(five, 5);
+        mapping.insert(six, 6);
+        mapping.insert(seven, 7);
+        mapping.insert(eight, 8);
+        mapping.insert(nine, 9);
+
+        self.outputs.iter().try_fold(0, |acc, output| {
+            mapping
+                .get(output)
+                .map(|digit| acc * 10 + digit)
+                .ok_or(UNSOLVABLE)
+        })
+    }
+}
+
+fn left_str_minus_right_str(a: &str, b: &str) -> Vec<char> {
+    a.chars()
+        .filter(|ca| !b.chars().any(|cb| cb == *ca))
+        .collect::<Vec<_>>()
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (inputs, outputs) = s
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::end_of_line(1, s, "' | '"))?;
+
+        let inputs = parse_patterns(s, inputs, 10)?;
+        let outputs = parse_patterns(s, outputs, 4)?;
+
+        Ok(Self { inputs, outputs })
+    }
+}
+
+/// Parses exactly `count` sorted segment patterns from `part`, a subslice of `line`.
+fn parse_patterns(line: &str, part: &str, count: usize) -> Result<Vec<String>, ParseError> {
+    let patterns = part.split_whitespace().collect::<Vec<_>>();
+
+    for pattern in &patterns {
+        if let Some((i, _)) = pattern
+            .char_indices()
+            .find(|(_, c)| !('a'..='g').contains(c))
+        {
+            return Err(ParseError::at(1, line, &pattern[i..], "segment a-g"));
+        }
+    }
+
+    match patterns.get(count) {
+        Some(extra) => Err(ParseError::at(1, line, extra, "' | ' or end of line")),
+        None if patterns.len() < count => Err(ParseError::at(
+            1,
+            line,
+            &part[part.len()..],
+            format!("{} patterns", count),
+        )),
+        None => Ok(patterns.into_iter().map(sort_str).collect()),
+    }
+}
+
+fn sort_str(input: &str) -> String {
+    let mut chars = input.chars().collect::<Vec<_>>();
+    chars.sort_unstable();
+    chars.iter().collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{NoAnswer, Solution};
+
+    use crate::Day08;
+
+    #[test]
+    fn test_part1() {
+        let input = Day08::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day08::part1(&input), 26);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day08::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day08::part2(&input), Ok(61229));
+    }
+
+    #[test]
+    fn test_part2_unsolvable() {
+        let input = Day08::parse("a a a a a a a a a a | a a a a").unwrap();
+        assert_eq!(
+            Day08::part2(&input),
+            Err(NoAnswer("the patterns do not map onto the ten digits"))
+        );
+    }
+}
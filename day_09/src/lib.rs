@@ -0,0 +1,112 @@
+use common::{parse::ParseError, Solution};
+use grid::{Grid, Point};
+use search::Neighbours;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = HeightMap;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(height_map: &Self::Input) -> Self::Part1 {
+        part1(height_map)
+    }
+
+    fn part2(height_map: &Self::Input) -> Self::Part2 {
+        height_map.find_three_longest_basins_product()
+    }
+}
+
+fn part1(height_map: &HeightMap) -> u32 {
+    height_map
+        .lowpoints()
+        .iter()
+        .map(|(_, height)| height + 1)
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<HeightMap, ParseError> {
+    let inner = Grid::parse_digits(input)?;
+
+    Ok(HeightMap { inner })
+}
+
+pub struct HeightMap {
+    inner: Grid<u32>,
+}
+
+impl HeightMap {
+    fn lowpoints(&self) -> Vec<(Point, u32)> {
+        let lowpoints = self
+            .inner
+            .iter()
+            .filter(|(position, height)| {
+                self.inner
+                    .neighbours4(*position)
+                    .all(|neighbour| **height < self.inner[neighbour])
+            })
+            .map(|(position, height)| (position, *height))
+            .collect::<Vec<_>>();
+
+        tracing::debug!(lowpoints = lowpoints.len());
+        lowpoints
+    }
+
+    fn find_three_longest_basins_product(&self) -> usize {
+        let mut basin_sizes = self
+            .lowpoints()
+            .iter()
+            .map(|(position, _)| self.track_basin_size(*position))
+            .collect::<Vec<_>>();
+
+        basin_sizes.sort_unstable();
+        basin_sizes.iter().rev().take(3).product()
+    }
+
+    fn track_basin_size(&self, start: Point) -> usize {
+        search::bfs(self, start).len()
+    }
+}
+
+/// Basins grow uphill from their low point until they hit a height of 9.
+impl Neighbours for HeightMap {
+    type Node = Point;
+    type Cost = u32;
+
+    fn neighbours(&self, position: &Point) -> impl Iterator<Item = (Point, u32)> {
+        let height = self.inner[*position];
+        self.inner
+            .neighbours4(*position)
+            .filter(move |neighbour| {
+                let neighbour_height = self.inner[*neighbour];
+                neighbour_height < 9 && neighbour_height > height
+            })
+            .map(|neighbour| (neighbour, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::Day09;
+
+    #[test]
+    fn test_part1() {
+        let input = Day09::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day09::part1(&input), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day09::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day09::part2(&input), 1134);
+    }
+}
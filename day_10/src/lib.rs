@@ -0,0 +1,176 @@
+use std::collections::VecDeque;
+
+use common::{checked::Overflow, parse::ParseError, NoAnswer, Solution, SolveError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = Result<u64, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_line(i + 1, l))
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        part1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        part2(lines)
+    }
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<String, ParseError> {
+    let chunks = line.trim();
+    match chunks
+        .char_indices()
+        .find(|(_, c)| !"([{<>}])".contains(*c))
+    {
+        Some((offset, c)) => {
+            let token = &chunks[offset..offset + c.len_utf8()];
+            Err(ParseError::at(line_number, line, token, "bracket"))
+        }
+        None => Ok(chunks.to_string()),
+    }
+}
+
+fn part1(lines: &[String]) -> usize {
+    let scores = lines
+        .iter()
+        .filter_map(|line| score(line).err())
+        .collect::<Vec<_>>();
+
+    tracing::debug!(lines = lines.len(), corrupted = scores.len());
+    scores.iter().sum()
+}
+
+fn part2(lines: &[String]) -> Result<u64, SolveError> {
+    let mut autocomplete_scores = lines
+        .iter()
+        .filter_map(|line| score(line).ok())
+        .map(|stack| {
+            // Five times as much per missing character, so a few dozen are enough to overflow
+            stack.iter().rev().try_fold(0u64, |acc, next| {
+                let rank = match next {
+                    '(' => 1,
+                    '[' => 2,
+                    '{' => 3,
+                    '<' => 4,
+                    _ => unreachable!(),
+                };
+                acc.checked_mul(5)?.checked_add(rank)
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Overflow("autocomplete score"))?;
+
+    autocomplete_scores.sort_unstable();
+    tracing::debug!(lines = lines.len(), incomplete = autocomplete_scores.len());
+
+    let index = autocomplete_scores.len() / 2;
+    let score = autocomplete_scores
+        .get(index)
+        .ok_or(NoAnswer("no line is incomplete"))?;
+    Ok(*score)
+}
+
+fn score(line: &str) -> Result<VecDeque<char>, usize> {
+    let mut stack = VecDeque::new();
+
+    for char in line.chars() {
+        match char {
+            '(' | '[' | '{' | '<' => {
+                stack.push_back(char);
+            }
+            ')' | ']' | '}' | '>' => {
+                let error_score = expect_next(&mut stack, char);
+                if error_score > 0 {
+                    return Err(error_score);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(stack)
+}
+
+fn expect_next(stack: &mut VecDeque<char>, expected: char) -> usize {
+    match (expected, stack.pop_back()) {
+        (')', Some('(')) => 0,
+        (']', Some('[')) => 0,
+        ('}', Some('{')) => 0,
+        ('>', Some('<')) => 0,
+        // Also corrupted when there is nothing left to close
+        _ => rank(expected),
+    }
+}
+
+fn rank(char: char) -> usize {
+    match char {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, parse::ParseError, NoAnswer, Solution, SolveError};
+
+    use crate::Day10;
+
+    #[test]
+    fn test_part1() {
+        let input = Day10::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day10::part1(&input), 26397);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day10::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day10::part2(&input), Ok(288957));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day10::parse(&"<".repeat(27)).unwrap();
+        assert_eq!(Day10::part2(&input), Ok(5u64.pow(27) - 1));
+
+        let input = Day10::parse(&"<".repeat(28)).unwrap();
+        assert_eq!(
+            Day10::part2(&input),
+            Err(SolveError::Overflow(Overflow("autocomplete score")))
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            Day10::parse("()\n  [x]"),
+            Err(ParseError::new(2, 4, "bracket", "x"))
+        );
+        assert_eq!(
+            Day10::parse("<1>"),
+            Err(ParseError::new(1, 2, "bracket", "1"))
+        );
+
+        let input = Day10::parse("())\n").unwrap();
+        assert_eq!(Day10::part1(&input), 3);
+        assert_eq!(
+            Day10::part2(&input),
+            Err(SolveError::NoAnswer(NoAnswer("no line is incomplete")))
+        );
+        assert!(Day10::part2(&Day10::parse("").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+use animation::{Animation, Color, Styled};
+use common::{parse::ParseError, Solution};
+use grid::Grid;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Cavern;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Cavern::from_str(input)
+    }
+
+    fn part1(cavern: &Self::Input) -> Self::Part1 {
+        solve(cavern.clone()).0
+    }
+
+    fn part2(cavern: &Self::Input) -> Self::Part2 {
+        solve(cavern.clone()).1
+    }
+}
+
+fn solve(mut cavern: Cavern) -> (usize, usize) {
+    let mut flashes_after_100 = 0;
+    let mut first_synchronized_flash = 0;
+
+    for step in 1..10000 {
+        let flashes = cavern.tick();
+        if step == 100 {
+            flashes_after_100 = cavern.total_flashes;
+        }
+        if flashes == cavern.inner.len() {
+            tracing::debug!(step, total_flashes = cavern.total_flashes, "synchronized");
+            first_synchronized_flash = step;
+            break;
+        }
+    }
+    (flashes_after_100, first_synchronized_flash)
+}
+
+#[derive(Clone)]
+pub struct Cavern {
+    inner: Grid<u32>,
+    total_flashes: usize,
+}
+
+impl Cavern {
+    fn tick(&mut self) -> usize {
+        let mut resets = HashSet::new();
+        let mut flashing = VecDeque::new();
+        let mut flashes = 0;
+
+        self.inner.iter_mut().for_each(|(position, value)| {
+            *value += 1;
+            if *value > 9 {
+                flashing.push_back(position);
+            }
+        });
+
+        while let Some(position) = flashing.pop_front() {
+            resets.insert(position);
+            flashes += 1;
+            for neighbour_position in self.inner.neighbours8(position).collect::<Vec<_>>() {
+                let neighbour = &mut self.inner[neighbour_position];
+                *neighbour += 1;
+                if *neighbour == 10 {
+                    flashing.push_back(neighbour_position);
+                    resets.insert(neighbour_position);
+                }
+            }
+        }
+
+        // Reset all flashed ones at the end
+        for r in &resets {
+            self.inner[*r] = 0;
+        }
+
+        self.total_flashes += flashes;
+        flashes
+    }
+}
+
+impl FromStr for Cavern {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = Grid::parse_digits(s)?;
+
+        Ok(Self {
+            inner,
+            total_flashes: 0,
+        })
+    }
+}
+/// Replays the octopuses step by step until they all flash at once.
+pub struct Flashes {
+    cavern: Cavern,
+    step: usize,
+    flashes: usize,
+}
+
+pub fn animation(cavern: &Cavern) -> Flashes {
+    Flashes {
+        cavern: cavern.clone(),
+        step: 0,
+        flashes: 0,
+    }
+}
+
+impl Animation for Flashes {
+    fn step(&mut self) -> bool {
+        self.flashes = self.cavern.tick();
+        self.step += 1;
+        self.flashes != self.cavern.inner.len()
+    }
+
+    fn frame(&self) -> Grid<Styled> {
+        self.cavern.inner.map(|energy| {
+            let symbol = char::from_digit(*energy, 10).unwrap_or('+');
+            // Octopuses that just flashed are back at 0
+            if *energy == 0 {
+                Styled::colored(symbol, Color::Yellow)
+            } else {
+                Styled::colored(symbol, Color::Gray)
+            }
+        })
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "step {}: {} flashes, {} in total",
+            self.step, self.flashes, self.cavern.total_flashes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::Day11;
+
+    #[test]
+    fn test_part1() {
+        let input = Day11::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day11::part1(&input), 1656);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day11::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day11::part2(&input), 195);
+    }
+}
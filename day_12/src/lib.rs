@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use common::{parse::ParseError, Solution};
+use search::Neighbours;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Self::Part1 {
+        solve(graph, 0)
+    }
+
+    fn part2(graph: &Self::Input) -> Self::Part2 {
+        solve(graph, 1)
+    }
+}
+
+pub type Graph = HashMap<String, Vec<String>>;
+
+/// Caves by index, with the small ones visited so far kept as a bitmask.
+struct Caves {
+    neighbours: Vec<Vec<usize>>,
+    small: Vec<bool>,
+    start: usize,
+    end: usize,
+}
+
+/// A walk through the caves so far: where it is, the small caves it has seen and how many
+/// of them it may still enter a second time.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Walk {
+    cave: usize,
+    visited: u64,
+    extra_visits: usize,
+}
+
+impl Caves {
+    fn new(graph: &Graph) -> Self {
+        let mut names = graph.keys().map(String::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        assert!(names.len() <= 64, "too many caves for the visited bitmask");
+        let index = |name: &str| names.binary_search(&name).unwrap();
+
+        Caves {
+            neighbours: names
+                .iter()
+                .map(|name| graph[*name].iter().map(|to| index(to)).collect())
+                .collect(),
+            small: names.iter().map(|name| !is_big(name)).collect(),
+            start: index("start"),
+            end: index("end"),
+        }
+    }
+}
+
+// Big caves are never connected to each other, so every walk keeps entering new small caves
+// or using up extra visits and the graph of walks is acyclic
+impl Neighbours for Caves {
+    type Node = Walk;
+    type Cost = usize;
+
+    fn neighbours(&self, walk: &Walk) -> impl Iterator<Item = (Walk, usize)> {
+        let walk = *walk;
+        self.neighbours[walk.cave]
+            .iter()
+            .filter(move |_| walk.cave != self.end)
+            .filter_map(move |&cave| {
+                let mut next = Walk { cave, ..walk };
+                if self.small[cave] {
+                    let bit = 1 << cave;
+                    if walk.visited & bit == 0 {
+                        next.visited |= bit;
+                    } else if walk.extra_visits > 0 && cave != self.start && cave != self.end {
+                        next.extra_visits -= 1;
+                    } else {
+                        return None;
+                    }
+                }
+                Some((next, 1))
+            })
+    }
+}
+
+fn solve(graph: &Graph, small_caves_extra_visits: usize) -> usize {
+    if !graph.contains_key("start") || !graph.contains_key("end") {
+        return 0;
+    }
+
+    let caves = Caves::new(graph);
+    let start = Walk {
+        cave: caves.start,
+        visited: 1 << caves.start,
+        extra_visits: small_caves_extra_visits,
+    };
+
+    search::count_paths(&caves, start, |walk| walk.cave == caves.end)
+}
+
+fn parse_input(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let (key, value) = parse_connection(i + 1, line)?;
+        if is_big(key) && is_big(value) {
+            // Walks could go back and forth between them forever
+            return Err(ParseError::at(i + 1, line, value, "a small cave"));
+        }
+
+        graph
+            .entry(key.to_string())
+            .or_default()
+            .push(value.to_string());
+        graph
+            .entry(value.to_string())
+            .or_default()
+            .push(key.to_string());
+        if graph.len() > 64 {
+            return Err(ParseError::at(i + 1, line, line.trim(), "at most 64 caves"));
+        }
+    }
+
+    Ok(graph)
+}
+
+fn parse_connection(line_number: usize, line: &str) -> Result<(&str, &str), ParseError> {
+    let connection = line.trim();
+    let (key, value) = connection
+        .split_once('-')
+        .ok_or_else(|| ParseError::end_of_line(line_number, line, "'-'"))?;
+
+    for cave in [key, value] {
+        if cave.is_empty() {
+            return Err(ParseError::at(line_number, line, cave, "cave name"));
+        }
+        if let Some((i, c)) = cave.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            let found = &cave[i..i + c.len_utf8()];
+            return Err(ParseError::at(line_number, line, found, "letter"));
+        }
+        // Mixed case would leave it unclear whether the cave is big or small
+        let big = is_big(&cave[..1]);
+        if let Some((i, c)) = cave
+            .char_indices()
+            .find(|(_, c)| c.is_ascii_uppercase() != big)
+        {
+            let found = &cave[i..i + c.len_utf8()];
+            let expected = if big {
+                "uppercase letter"
+            } else {
+                "lowercase letter"
+            };
+            return Err(ParseError::at(line_number, line, found, expected));
+        }
+    }
+
+    Ok((key, value))
+}
+
+fn is_big(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse::ParseError, Solution};
+
+    use crate::{parse_input, Day12};
+
+    #[test]
+    fn test_part1() {
+        let input = Day12::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day12::part1(&input), 10);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day12::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day12::part2(&input), 36);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("start-A\nA-b\nb_end").err();
+        assert_eq!(error, Some(ParseError::new(3, 6, "'-'", "")));
+
+        let error = parse_input("start-A\nA-B").err();
+        assert_eq!(error, Some(ParseError::new(2, 3, "a small cave", "B")));
+
+        let error = parse_input("start-Ab\nAb-CD\nCD-end").err();
+        assert_eq!(error, Some(ParseError::new(1, 8, "uppercase letter", "b")));
+
+        let error = parse_input("start-A\nA-\n").err();
+        assert_eq!(error, Some(ParseError::new(2, 3, "cave name", "")));
+    }
+}
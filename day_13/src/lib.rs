@@ -0,0 +1,347 @@
+use std::{collections::HashSet, str::FromStr};
+
+use animation::{Animation, Color, Styled};
+use common::{
+    checked::Overflow,
+    parse::{self, ParseError},
+    NoAnswer, Solution, SolveError,
+};
+use grid::Grid;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Paper;
+    type Part1 = Result<usize, Overflow>;
+    type Part2 = Result<String, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Paper::from_str(input)
+    }
+
+    fn part1(paper: &Self::Input) -> Self::Part1 {
+        solve(paper.points.clone(), paper.instructions.clone(), 1).map(|points| points.len())
+    }
+
+    fn part2(paper: &Self::Input) -> Self::Part2 {
+        let points = solve(paper.points.clone(), paper.instructions.clone(), usize::MAX)?;
+        Ok(render_points(&points)?)
+    }
+}
+
+/// Most cells the folded paper may take up when rendered, a fold leaves dots on its near
+/// side wherever they are
+const MAX_AREA: u64 = 1_000_000;
+
+/// Most cells the animation shows across or down, the rest of the paper is cut off
+const MAX_FRAME_SIDE: u32 = 1000;
+
+fn render_points(points: &HashSet<(u32, u32)>) -> Result<String, NoAnswer> {
+    let (max_x, max_y) = points
+        .iter()
+        .fold((0, 0), |acc, next| (acc.0.max(next.0), acc.1.max(next.1)));
+    if (u64::from(max_x) + 1) * (u64::from(max_y) + 1) > MAX_AREA {
+        return Err(NoAnswer("the folded dots are too far apart to render"));
+    }
+
+    let mut output = String::new();
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            output.push(points.get(&(x, y)).map(|_| '#').unwrap_or(' '));
+        }
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+fn solve(
+    points: HashSet<(u32, u32)>,
+    instructions: Vec<(char, u32)>,
+    folds: usize,
+) -> Result<HashSet<(u32, u32)>, Overflow> {
+    instructions[0..folds.min(instructions.len())]
+        .iter()
+        .try_fold(points, |acc, (c, v)| fold(acc, *c, *v))
+}
+
+/// Folds the points past the line onto the other side. A point further from the line than
+/// the edge of the paper would land at a negative coordinate, which is an error, as is a line
+/// so far out that its mirror image does not fit a `u32`.
+fn fold(
+    mut points: HashSet<(u32, u32)>,
+    axis: char,
+    value: u32,
+) -> Result<HashSet<(u32, u32)>, Overflow> {
+    let mirror = |coordinate: u32| {
+        value
+            .checked_mul(2)
+            .and_then(|v| v.checked_sub(coordinate))
+            .ok_or(Overflow("folded coordinate"))
+    };
+    let to_map = points
+        .iter()
+        .filter_map(|&(x, y)| match axis {
+            'y' if y > value => Some(mirror(y).map(|y2| ((x, y), (x, y2)))),
+            'x' if x > value => Some(mirror(x).map(|x2| ((x, y), (x2, y)))),
+            _ => None,
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let moved = to_map.len();
+
+    for (old_point, new_point) in to_map {
+        points.remove(&old_point);
+        points.insert(new_point);
+    }
+
+    tracing::debug!(%axis, value, moved, points = points.len(), "folded");
+    Ok(points)
+}
+
+#[derive(Clone)]
+pub struct Paper {
+    points: HashSet<(u32, u32)>,
+    instructions: Vec<(char, u32)>,
+}
+
+impl FromStr for Paper {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let blocks = parse::blocks(s);
+        let (points_start, raw_points) = blocks
+            .first()
+            .ok_or_else(|| ParseError::new(1, 1, "dot coordinates", ""))?;
+        let (instructions_start, raw_instructions) = blocks.get(1).ok_or_else(|| {
+            let line = points_start + raw_points.lines().count() + 2;
+            ParseError::new(line, 1, "fold instructions", "")
+        })?;
+
+        let points = raw_points
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_point(points_start + i + 1, l))
+            .collect::<Result<_, _>>()?;
+
+        let instructions = raw_instructions
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_instruction(instructions_start + i + 1, l))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Paper {
+            points,
+            instructions,
+        })
+    }
+}
+
+fn parse_point(line_number: usize, line: &str) -> Result<(u32, u32), ParseError> {
+    let point = line.trim();
+    let (x, y) = point
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(line_number, line, point, "x,y"))?;
+
+    Ok((
+        parse::number(line_number, line, x)?,
+        parse::number(line_number, line, y)?,
+    ))
+}
+
+fn parse_instruction(line_number: usize, line: &str) -> Result<(char, u32), ParseError> {
+    let instruction = line.trim();
+    let fold = instruction
+        .strip_prefix("fold along ")
+        .ok_or_else(|| ParseError::at(line_number, line, instruction, "'fold along'"))?;
+
+    let axis = match fold.chars().next() {
+        Some(axis @ ('x' | 'y')) => axis,
+        Some(_) => return Err(ParseError::at(line_number, line, &fold[..1], "x or y")),
+        None => return Err(ParseError::end_of_line(line_number, line, "x or y")),
+    };
+
+    let value = fold[1..]
+        .strip_prefix('=')
+        .ok_or_else(|| ParseError::at(line_number, line, &fold[1..], "'='"))?;
+
+    Ok((axis, parse::number(line_number, line, value)?))
+}
+
+/// Replays the folds one by one, marking the line of the next fold.
+pub struct Folding {
+    points: HashSet<(u32, u32)>,
+    instructions: Vec<(char, u32)>,
+    folds: usize,
+    width: u32,
+    height: u32,
+}
+
+pub fn animation(paper: &Paper) -> Folding {
+    let (max_x, max_y) = paper
+        .points
+        .iter()
+        .fold((0, 0), |acc, next| (acc.0.max(next.0), acc.1.max(next.1)));
+
+    Folding {
+        points: paper.points.clone(),
+        instructions: paper.instructions.clone(),
+        folds: 0,
+        width: max_x + 1,
+        height: max_y + 1,
+    }
+}
+
+impl Animation for Folding {
+    fn step(&mut self) -> bool {
+        let Some(&(axis, value)) = self.instructions.get(self.folds) else {
+            return false;
+        };
+
+        // A fold that fails leaves the paper as it is and ends the animation
+        let Ok(points) = fold(self.points.clone(), axis, value) else {
+            return false;
+        };
+        self.points = points;
+        match axis {
+            'x' => self.width = value,
+            _ => self.height = value,
+        }
+        self.folds += 1;
+        self.folds < self.instructions.len()
+    }
+
+    fn frame(&self) -> Grid<Styled> {
+        let mut frame = Grid::new(
+            self.width.min(MAX_FRAME_SIDE) as usize,
+            self.height.min(MAX_FRAME_SIDE) as usize,
+            Styled::plain(' '),
+        );
+
+        match self.instructions.get(self.folds) {
+            Some(('x', value)) => {
+                for y in 0..frame.height() {
+                    if let Some(cell) = frame.get_mut((*value as usize, y)) {
+                        *cell = Styled::colored('|', Color::Red);
+                    }
+                }
+            }
+            Some((_, value)) => {
+                for x in 0..frame.width() {
+                    if let Some(cell) = frame.get_mut((x, *value as usize)) {
+                        *cell = Styled::colored('-', Color::Red);
+                    }
+                }
+            }
+            None => {}
+        }
+
+        for (x, y) in &self.points {
+            if let Some(cell) = frame.get_mut((*x as usize, *y as usize)) {
+                *cell = Styled::colored('#', Color::Green);
+            }
+        }
+
+        frame
+    }
+
+    fn caption(&self) -> String {
+        format!(
+            "fold {}/{}: {} dots",
+            self.folds,
+            self.instructions.len(),
+            self.points.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use animation::Animation;
+    use common::{checked::Overflow, parse::ParseError, NoAnswer, Solution, SolveError};
+    use proptest::prelude::*;
+
+    use crate::{animation, fold, render_points, solve, Day13, Paper};
+
+    #[test]
+    fn test_fold() {
+        let paper = Paper::from_str(include_str!("test.input.txt")).unwrap();
+        let points = solve(paper.points.clone(), paper.instructions.clone(), 1).unwrap();
+        assert_eq!(points.len(), 17);
+
+        let part2 = solve(paper.points, paper.instructions, usize::MAX).unwrap();
+        assert_eq!(
+            render_points(&part2),
+            Ok("#####\n#   #\n#   #\n#   #\n#####\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_too_far_apart() {
+        let paper = Day13::parse("0,4000000000\n3,0\n\nfold along x=2").unwrap();
+        assert_eq!(
+            Day13::part2(&paper),
+            Err(SolveError::NoAnswer(NoAnswer(
+                "the folded dots are too far apart to render"
+            )))
+        );
+
+        let frame = animation(&paper).frame();
+        assert_eq!((frame.width(), frame.height()), (4, 1000));
+    }
+
+    #[test]
+    fn test_fold_past_edge() {
+        for input in [
+            "1,1\n\nfold along x=0",
+            "1,1\n\nfold along y=0",
+            "4000000000,0\n\nfold along x=3000000000",
+        ] {
+            let paper = Paper::from_str(input).unwrap();
+            assert_eq!(
+                solve(paper.points, paper.instructions, 1),
+                Err(Overflow("folded coordinate"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Paper::from_str("6,10\n0,14\n\nfold along y=7\nfold along z=5").err();
+        assert_eq!(error, Some(ParseError::new(5, 12, "x or y", "z")));
+
+        let error = Paper::from_str("6,10\n0;14\n\nfold along y=7").err();
+        assert_eq!(error, Some(ParseError::new(2, 1, "x,y", "0;14")));
+
+        let error = Paper::from_str("6,10\n0,14\n").err();
+        assert_eq!(error, Some(ParseError::new(4, 1, "fold instructions", "")));
+    }
+
+    proptest! {
+        #[test]
+        fn test_fold_never_adds_points(
+            value in 1..100u32,
+            axis in prop::sample::select(vec!['x', 'y']),
+            points in prop::collection::hash_set((0..=100u32, 0..=100u32), 0..200),
+        ) {
+            let coordinate = |&(x, y): &(u32, u32)| if axis == 'x' { x } else { y };
+            // From 50 on every point folds onto the paper, below that some may land past its edge
+            let past_edge = points.iter().any(|point| coordinate(point) > 2 * value);
+            let count = points.len();
+
+            match fold(points, axis, value) {
+                Ok(folded) => {
+                    prop_assert!(!past_edge);
+                    prop_assert!(folded.len() <= count);
+                    prop_assert!(folded.iter().all(|point| coordinate(point) <= value));
+                }
+                Err(error) => {
+                    prop_assert!(past_edge);
+                    prop_assert_eq!(error, Overflow("folded coordinate"));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::{collections::HashMap, hash::Hash};
+
+use common::{
+    checked::{Count, Overflow},
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day14;
+
+/// Pair insertion rules, mapping each pair of elements to the one inserted between them.
+pub type Rules = HashMap<(char, char), char>;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = (Vec<char>, Rules);
+    type Part1 = Result<Quantity, Overflow>;
+    type Part2 = Result<Quantity, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((start, mapping): &Self::Input) -> Self::Part1 {
+        solve(start, mapping, 10)
+    }
+
+    fn part2((start, mapping): &Self::Input) -> Self::Part2 {
+        solve(start, mapping, 40)
+    }
+}
+
+/// The polymer about doubles with every step, so `u64` runs out after about 60 of them.
+#[cfg(not(feature = "bigint"))]
+pub type Quantity = u64;
+#[cfg(feature = "bigint")]
+pub type Quantity = common::checked::Big;
+
+fn solve<C: Count>(start: &[char], mapping: &Rules, iterations: usize) -> Result<C, Overflow> {
+    let one = C::from(1);
+    let mut pair_counts = HashMap::new();
+    for w in start.windows(2) {
+        add(&mut pair_counts, (w[0], w[1]), &one)?;
+    }
+
+    let mut total_counts = HashMap::<char, C>::new();
+
+    for _ in 0..iterations {
+        let mut next = HashMap::new();
+        for ((left, right), count) in pair_counts {
+            if let Some(m) = mapping.get(&(left, right)) {
+                add(&mut next, (left, *m), &count)?;
+                add(&mut next, (*m, right), &count)?;
+            }
+        }
+        pair_counts = next;
+    }
+    tracing::debug!(iterations, pairs = pair_counts.len());
+
+    for ((left, _), count) in pair_counts {
+        add(&mut total_counts, left, &count)?;
+    }
+    add(&mut total_counts, *start.last().unwrap(), &one)?;
+
+    let min = total_counts.values().min().unwrap();
+    let max = total_counts.values().max().unwrap();
+    Ok(max.checked_sub(min).unwrap())
+}
+
+fn add<K: Eq + Hash, C: Count>(
+    counts: &mut HashMap<K, C>,
+    key: K,
+    count: &C,
+) -> Result<(), Overflow> {
+    let total = counts.entry(key).or_insert_with(|| C::from(0));
+    *total = total.checked_add(count).ok_or(Overflow("element count"))?;
+    Ok(())
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Rules), ParseError> {
+    let blocks = parse::blocks(input);
+    let (template_start, template) = blocks
+        .first()
+        .ok_or_else(|| ParseError::new(1, 1, "polymer template", ""))?;
+    if let Some(extra) = template.lines().nth(1) {
+        return Err(ParseError::at(
+            template_start + 2,
+            extra,
+            extra,
+            "blank line",
+        ));
+    }
+    let start = parse_elements(template_start + 1, template, template.trim())?;
+
+    let mapping = match blocks.get(1) {
+        Some((rules_start, rules)) => rules
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_rule(rules_start + i + 1, l))
+            .collect::<Result<_, _>>()?,
+        None => HashMap::new(),
+    };
+
+    Ok((start, mapping))
+}
+
+fn parse_rule(line_number: usize, line: &str) -> Result<((char, char), char), ParseError> {
+    let rule = line.trim();
+    let (left, right) = rule
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::end_of_line(line_number, line, "' -> '"))?;
+
+    match (
+        parse_elements(line_number, line, left)?.as_slice(),
+        parse_elements(line_number, line, right)?.as_slice(),
+    ) {
+        (&[a, b], &[c]) => Ok(((a, b), c)),
+        ([_, _], _) => Err(ParseError::at(line_number, line, right, "single element")),
+        _ => Err(ParseError::at(line_number, line, left, "pair of elements")),
+    }
+}
+
+/// Elements are single uppercase letters.
+fn parse_elements(line_number: usize, line: &str, token: &str) -> Result<Vec<char>, ParseError> {
+    match token.char_indices().find(|(_, c)| !c.is_ascii_uppercase()) {
+        Some((i, c)) => Err(ParseError::at(
+            line_number,
+            line,
+            &token[i..i + c.len_utf8()],
+            "element A-Z",
+        )),
+        None => Ok(token.chars().collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, parse::ParseError, Answer, IntoAnswer, Solution};
+
+    use crate::{parse_input, solve, Day14};
+
+    #[test]
+    fn test_part1() {
+        let input = Day14::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day14::part1(&input).into_answer(), Ok(Answer::Number(1588)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day14::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(
+            Day14::part2(&input).into_answer(),
+            Ok(Answer::Number(2188189693529))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let (start, mapping) = Day14::parse(include_str!("test.input.txt")).unwrap();
+        assert!(solve::<u64>(&start, &mapping, 50).is_ok());
+        assert_eq!(
+            solve::<u64>(&start, &mapping, 70),
+            Err(Overflow("element count"))
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint() {
+        use common::checked::Big;
+
+        let (start, mapping) = Day14::parse(include_str!("test.input.txt")).unwrap();
+        let difference = solve::<Big>(&start, &mapping, 70).unwrap();
+        assert!(difference > Big::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("NNCB\n\nCH -> B\nHH => N").err();
+        assert_eq!(error, Some(ParseError::new(4, 8, "' -> '", "")));
+
+        let error = parse_input("NNCB\n\nCH -> B\nH -> N").err();
+        assert_eq!(error, Some(ParseError::new(4, 1, "pair of elements", "H")));
+
+        let error = parse_input("NnCB\n\nCH -> B").err();
+        assert_eq!(error, Some(ParseError::new(1, 2, "element A-Z", "n")));
+    }
+}
@@ -0,0 +1,131 @@
+use common::{parse::ParseError, Solution};
+use grid::{Grid, Point};
+use search::{Costs, Neighbours};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Part1 {
+        let end = (map.width() - 1, map.height() - 1);
+        solve(map, (0, 0), end)
+    }
+
+    fn part2(map: &Self::Input) -> Self::Part2 {
+        let extended_map = extend_map_tiles(map);
+        let end = (extended_map.width() - 1, extended_map.height() - 1);
+        solve(&extended_map, (0, 0), end)
+    }
+}
+
+/// Risk map seen as a graph, where entering a position costs its risk level.
+struct RiskGraph<'a>(&'a Grid<usize>);
+
+impl Neighbours for RiskGraph<'_> {
+    type Node = Point;
+    type Cost = usize;
+
+    fn neighbours(&self, position: &Point) -> impl Iterator<Item = (Point, usize)> {
+        self.0
+            .neighbours4(*position)
+            .map(move |neighbour| (neighbour, self.0[neighbour]))
+    }
+}
+
+/// Lowest total risk known so far for every position, `usize::MAX` where there is none yet.
+/// Far leaner than a map on the extended maps, which have millions of positions.
+struct Risks(Grid<usize>);
+
+impl Costs<Point, usize> for Risks {
+    fn get(&self, position: &Point) -> Option<usize> {
+        Some(self.0[*position]).filter(|risk| *risk != usize::MAX)
+    }
+
+    fn set(&mut self, position: &Point, risk: usize) {
+        self.0[*position] = risk;
+    }
+}
+
+fn solve(map: &Grid<usize>, start: Point, end: Point) -> usize {
+    let mut risks = Risks(Grid::new(map.width(), map.height(), usize::MAX));
+    search::cheapest(
+        &RiskGraph(map),
+        start,
+        |position| *position == end,
+        &mut risks,
+    )
+    .map(|(_, risk)| risk)
+    .expect("end should be reachable")
+}
+
+fn parse_input(input: &str) -> Result<Grid<usize>, ParseError> {
+    let map = Grid::parse_digits(input)?;
+    // The path needs a position to start and end on
+    if map.width() == 0 || map.height() == 0 {
+        return Err(ParseError::new(1, 1, "digit", ""));
+    }
+    Ok(map.map(|risk| *risk as usize))
+}
+
+#[cfg(test)]
+fn parse_extended_map(input: &str) -> Grid<usize> {
+    extend_map_tiles(&parse_input(input).unwrap())
+}
+
+/// Repeats the map five times in each direction, increasing the risk by one per tile step.
+fn extend_map_tiles(map: &Grid<usize>) -> Grid<usize> {
+    let (width, height) = (map.width(), map.height());
+    let mut extended = Grid::new(width * 5, height * 5, 0);
+
+    for ((x, y), risk) in extended.iter_mut() {
+        let tile_distance = x / width + y / height;
+        let mut new_value = map[(x % width, y % height)] + tile_distance;
+        if new_value > 9 {
+            new_value %= 10;
+            new_value += 1;
+        }
+        *risk = new_value;
+    }
+
+    extended
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::{parse_extended_map, parse_input};
+
+    #[test]
+    fn test_map_extension() {
+        let extended = parse_extended_map(include_str!("test.input.txt"));
+        let big = parse_input(include_str!("test.input.extended.txt")).unwrap();
+
+        assert_eq!(extended, big);
+    }
+
+    #[test]
+    fn test_map_extension_small() {
+        let extended = parse_extended_map("8");
+        let big = parse_input("89123\n91234\n12345\n23456\n34567").unwrap();
+
+        assert_eq!(extended[(3, 4)], 6);
+        assert_eq!(extended[(4, 4)], 7);
+        assert_eq!(extended, big);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_input(""), Err(ParseError::new(1, 1, "digit", "")));
+        assert_eq!(parse_input("\n\n"), Err(ParseError::new(1, 1, "digit", "")));
+    }
+}
@@ -0,0 +1,531 @@
+use std::fmt::Display;
+
+use common::{checked::Overflow, parse::ParseError, NoAnswer, Solution, SolveError};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type Part1 = usize;
+    type Part2 = Result<u64, SolveError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut raw_packet = parse_input(input)?;
+        let packet = parse_packet(&mut raw_packet, 0)?;
+        tracing::debug!(bits = raw_packet.position, "packet parsed");
+        Ok(packet)
+    }
+
+    fn part1(root_packet: &Self::Input) -> Self::Part1 {
+        sum_version_numbers(root_packet)
+    }
+
+    fn part2(root_packet: &Self::Input) -> Self::Part2 {
+        evaluate_packet(root_packet)
+    }
+}
+
+fn sum_version_numbers(root_packet: &Packet) -> usize {
+    match root_packet {
+        Packet::Literal(l) => l.version as usize,
+        Packet::Operation(op) => {
+            op.subpackets.iter().map(sum_version_numbers).sum::<usize>() + op.version as usize
+        }
+    }
+}
+
+/// Value of the expression, failing when a sum or product does not fit a `u64` or an operator
+/// has sub-packets it cannot be applied to.
+fn evaluate_packet(packet: &Packet) -> Result<u64, SolveError> {
+    match packet {
+        Packet::Literal(l) => Ok(l.literal),
+        Packet::Operation(op) => {
+            let values = op
+                .subpackets
+                .iter()
+                .map(evaluate_packet)
+                .collect::<Result<Vec<_>, _>>()?;
+            let value = match (op.type_id, values.as_slice()) {
+                (0, [first, rest @ ..]) => rest
+                    .iter()
+                    .try_fold(*first, |sum, value| sum.checked_add(*value))
+                    .ok_or(Overflow("packet sum"))?,
+                (1, [first, rest @ ..]) => rest
+                    .iter()
+                    .try_fold(*first, |product, value| product.checked_mul(*value))
+                    .ok_or(Overflow("packet product"))?,
+                (2, [first, rest @ ..]) => rest.iter().fold(*first, |min, value| min.min(*value)),
+                (3, [first, rest @ ..]) => rest.iter().fold(*first, |max, value| max.max(*value)),
+                (5, [a, b]) => (a > b) as u64,
+                (6, [a, b]) => (a < b) as u64,
+                (7, [a, b]) => (a == b) as u64,
+                _ => {
+                    return Err(NoAnswer("an operator cannot be applied to its sub-packets").into())
+                }
+            };
+            Ok(value)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Packet {
+    Operation(Operation),
+    Literal(Literal),
+}
+
+impl Packet {
+    pub fn literal(version: u32, literal: u64) -> Self {
+        Packet::Literal(Literal {
+            version,
+            type_id: 4,
+            literal,
+        })
+    }
+
+    /// An operator, which has to be a sum, product, minimum or maximum of at least one
+    /// sub-packet (type ids 0 to 3) or a comparison of two (type ids 5 to 7).
+    pub fn operation(
+        version: u32,
+        type_id: u32,
+        subpackets: Vec<Packet>,
+    ) -> Result<Self, InvalidPacket> {
+        match (type_id, subpackets.len()) {
+            (0..=3, 0) => Err(InvalidPacket("operator without sub-packets")),
+            (0..=3, _) | (5..=7, 2) => Ok(Packet::Operation(Operation {
+                version,
+                type_id,
+                subpackets,
+            })),
+            (5..=7, _) => Err(InvalidPacket("comparison of other than 2 sub-packets")),
+            _ => Err(InvalidPacket("operator type id")),
+        }
+    }
+}
+
+/// An operator packet that cannot be evaluated, naming what is wrong with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPacket(pub &'static str);
+
+impl Display for InvalidPacket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid packet: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPacket {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Operation {
+    version: u32,
+    type_id: u32,
+    subpackets: Vec<Packet>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Literal {
+    version: u32,
+    type_id: u32,
+    literal: u64,
+}
+
+/// The bits of a transmission, read from the front.
+pub struct Bits {
+    bits: Vec<bool>,
+    position: usize,
+}
+
+impl Bits {
+    fn take_n(&mut self, n: usize) -> Result<usize, ParseError> {
+        let field = self
+            .bits
+            .get(self.position..self.position + n)
+            .ok_or_else(|| self.error(format!("{}-bit field", n), ""))?;
+        self.position += n;
+        Ok(field
+            .iter()
+            .fold(0, |value, bit| (value << 1) | *bit as usize))
+    }
+
+    /// Error at the hex digit holding the current bit.
+    fn error(&self, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError::new(1, self.position / 4 + 1, expected, found)
+    }
+}
+
+/// Most operators a packet may be nested in. Parsing, evaluating and encoding all recurse
+/// once per level.
+pub const MAX_DEPTH: usize = 250;
+
+/// Parses the packet at the current bit, which sits inside `depth` operators.
+fn parse_packet(raw: &mut Bits, depth: usize) -> Result<Packet, ParseError> {
+    let version = raw.take_n(3)? as u32;
+    let type_id = raw.take_n(3)? as u32;
+    if type_id == 4 {
+        // literal value packet
+        let literal = parse_literal(raw)?;
+        return Ok(Packet::Literal(Literal {
+            version,
+            type_id,
+            literal,
+        }));
+    }
+
+    // operator packet
+    if depth == MAX_DEPTH {
+        let expected = format!("at most {} nested operators", MAX_DEPTH);
+        return Err(raw.error(expected, "another operator"));
+    }
+    let length_type_id = raw.take_n(1)?;
+    let subpackets = if length_type_id == 0 {
+        // parse total length operator packet
+        let n_bits = raw.take_n(15)?;
+        parse_n_bits(raw, n_bits, depth + 1)?
+    } else {
+        // parse total sub-packets operator packet
+        let n_packets = raw.take_n(11)?;
+        parse_n_packets(raw, n_packets, depth + 1)?
+    };
+
+    match (type_id, subpackets.len()) {
+        (0..=3, 0) => Err(raw.error("at least 1 sub-packet", "0")),
+        (5..=7, n) if n != 2 => Err(raw.error("2 sub-packets", n.to_string())),
+        _ => Ok(Packet::Operation(Operation {
+            version,
+            type_id,
+            subpackets,
+        })),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Bits, ParseError> {
+    let line = input.trim();
+    let mut bits = vec![];
+
+    for (column, ch) in line.chars().enumerate() {
+        let v = ch
+            .to_digit(16)
+            .ok_or_else(|| ParseError::new(1, column + 1, "hex digit", ch))?;
+        bits.extend((0..4).rev().map(|bit| v & (1 << bit) != 0));
+    }
+
+    Ok(Bits { bits, position: 0 })
+}
+
+fn parse_literal(raw: &mut Bits) -> Result<u64, ParseError> {
+    let mut literal = 0u64;
+
+    loop {
+        let last = raw.take_n(1)? == 0;
+        let group = raw.take_n(4)?;
+        if literal.leading_zeros() < 4 {
+            return Err(raw.error("literal of at most 64 bits", "more groups"));
+        }
+        literal = (literal << 4) | group as u64;
+
+        if last {
+            return Ok(literal);
+        }
+    }
+}
+
+fn parse_n_packets(
+    raw: &mut Bits,
+    n_packets: usize,
+    depth: usize,
+) -> Result<Vec<Packet>, ParseError> {
+    let mut packets = vec![];
+    for _ in 0..n_packets {
+        packets.push(parse_packet(raw, depth)?);
+    }
+    Ok(packets)
+}
+
+fn parse_n_bits(raw: &mut Bits, n_bits: usize, depth: usize) -> Result<Vec<Packet>, ParseError> {
+    let end = raw.position + n_bits;
+    let mut packets = vec![];
+
+    while raw.position < end {
+        packets.push(parse_packet(raw, depth)?);
+    }
+
+    if raw.position > end {
+        let overrun = format!("{} bits", raw.position - end + n_bits);
+        return Err(raw.error(format!("sub-packets of {} bits", n_bits), overrun));
+    }
+
+    Ok(packets)
+}
+
+/// Encodes a packet as a hexadecimal transmission, the inverse of parsing. Fails when the
+/// version, type id or sub-packet count do not fit their 3, 3 and 11 bits.
+///
+/// Operators always use the sub-packet count as length type, so a packet that was parsed
+/// from a total bit length will encode differently but still decode to the same packet.
+pub fn encode(packet: &Packet) -> Result<String, Overflow> {
+    let mut bits = String::new();
+    encode_packet(packet, &mut bits)?;
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+
+    Ok(bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = std::str::from_utf8(nibble).unwrap();
+            let value = u32::from_str_radix(nibble, 2).unwrap();
+            std::char::from_digit(value, 16)
+                .unwrap()
+                .to_ascii_uppercase()
+        })
+        .collect())
+}
+
+fn encode_packet(packet: &Packet, bits: &mut String) -> Result<(), Overflow> {
+    match packet {
+        Packet::Literal(l) => {
+            push_n(bits, l.version as usize, 3, "packet version")?;
+            push_n(bits, l.type_id as usize, 3, "packet type id")?;
+
+            let groups = (u64::BITS - l.literal.leading_zeros()).max(1).div_ceil(4);
+            for group in (0..groups).rev() {
+                push_n(bits, (group > 0) as usize, 1, "literal group")?;
+                push_n(
+                    bits,
+                    ((l.literal >> (group * 4)) & 0xF) as usize,
+                    4,
+                    "literal group",
+                )?;
+            }
+        }
+        Packet::Operation(op) => {
+            push_n(bits, op.version as usize, 3, "packet version")?;
+            push_n(bits, op.type_id as usize, 3, "packet type id")?;
+            push_n(bits, 1, 1, "length type id")?;
+            push_n(bits, op.subpackets.len(), 11, "sub-packet count")?;
+            for subpacket in &op.subpackets {
+                encode_packet(subpacket, bits)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Appends the lowest `n` bits of `value`, which must not have any others set.
+fn push_n(bits: &mut String, value: usize, n: usize, what: &'static str) -> Result<(), Overflow> {
+    if value >> n != 0 {
+        return Err(Overflow(what));
+    }
+    bits.push_str(&format!("{:0width$b}", value, width = n));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, parse::ParseError, NoAnswer, Solution};
+    use proptest::prelude::*;
+
+    use crate::{
+        encode, parse_input, parse_packet, sum_version_numbers, Day16, InvalidPacket, Literal,
+        Operation, Packet, MAX_DEPTH,
+    };
+
+    #[test]
+    fn test_parse_packet() {
+        let mut raw = parse_input("38006F45291200").unwrap();
+        let packet = parse_packet(&mut raw, 0).unwrap();
+
+        assert_eq!(
+            packet,
+            Packet::Operation(Operation {
+                version: 1,
+                type_id: 6,
+                subpackets: vec![
+                    Packet::Literal(Literal {
+                        version: 6,
+                        type_id: 4,
+                        literal: 10,
+                    },),
+                    Packet::Literal(Literal {
+                        version: 2,
+                        type_id: 4,
+                        literal: 20,
+                    },),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_sum_version_numbers() {
+        let mut raw = parse_input("8A004A801A8002F478").unwrap();
+        let packet = parse_packet(&mut raw, 0).unwrap();
+        assert_eq!(sum_version_numbers(&packet), 16);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = Packet::literal(0, u64::MAX / 2 + 1);
+        let sum = Packet::operation(0, 0, vec![big.clone(), big.clone()]).unwrap();
+        assert_eq!(Day16::part2(&sum), Err(Overflow("packet sum").into()));
+
+        let product = Packet::operation(0, 1, vec![big.clone(), Packet::literal(0, 1)]).unwrap();
+        assert_eq!(Day16::part2(&product), Ok(u64::MAX / 2 + 1));
+        let product = Packet::operation(0, 1, vec![big, Packet::literal(0, 2)]).unwrap();
+        assert_eq!(
+            Day16::part2(&product),
+            Err(Overflow("packet product").into())
+        );
+
+        // Operators built around the validating constructor
+        let cannot_apply = Err(NoAnswer("an operator cannot be applied to its sub-packets").into());
+        for (type_id, subpackets) in [(2, 0), (4, 1), (6, 3), (8, 2)] {
+            let operation = Packet::Operation(Operation {
+                version: 0,
+                type_id,
+                subpackets: vec![Packet::literal(0, 1); subpackets],
+            });
+            assert_eq!(Day16::part2(&operation), cannot_apply);
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        let packet = parse_packet(&mut parse_input("D2FE28").unwrap(), 0).unwrap();
+        assert_eq!(encode(&packet), Ok("D2FE28".to_string()));
+    }
+
+    #[test]
+    fn test_encode_limits() {
+        let literals = |n| (0..n).map(|i| Packet::literal(7, i)).collect::<Vec<_>>();
+
+        let widest = Packet::operation(7, 0, literals(2047)).unwrap();
+        let decoded = parse_packet(&mut parse_input(&encode(&widest).unwrap()).unwrap(), 0);
+        assert_eq!(decoded, Ok(widest));
+        let compare = Packet::operation(7, 7, literals(2)).unwrap();
+        let decoded = parse_packet(&mut parse_input(&encode(&compare).unwrap()).unwrap(), 0);
+        assert_eq!(decoded, Ok(compare));
+
+        let too_wide = Packet::operation(0, 0, literals(2048)).unwrap();
+        assert_eq!(encode(&too_wide), Err(Overflow("sub-packet count")));
+        let version = Packet::operation(8, 0, literals(1)).unwrap();
+        assert_eq!(encode(&version), Err(Overflow("packet version")));
+    }
+
+    #[test]
+    fn test_invalid_operation() {
+        let literals = |n| (0..n).map(|i| Packet::literal(0, i)).collect::<Vec<_>>();
+        let type_id = Err(InvalidPacket("operator type id"));
+        assert_eq!(Packet::operation(0, 4, literals(1)), type_id);
+        assert_eq!(Packet::operation(0, 8, literals(1)), type_id);
+        assert_eq!(
+            Packet::operation(0, 2, vec![]),
+            Err(InvalidPacket("operator without sub-packets"))
+        );
+        for n in [0, 1, 3] {
+            assert_eq!(
+                Packet::operation(0, 6, literals(n)),
+                Err(InvalidPacket("comparison of other than 2 sub-packets"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day16::parse("D2FG28").err();
+        assert_eq!(error, Some(ParseError::new(1, 4, "hex digit", "G")));
+
+        // Literal cut off in its second group
+        let error = Day16::parse("D2F").err();
+        assert_eq!(error, Some(ParseError::new(1, 4, "4-bit field", "")));
+
+        // Sum without any sub-packets
+        let error = Day16::parse("02000").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(1, 5, "at least 1 sub-packet", "0"))
+        );
+
+        // Sub-packets claiming 10 bits but taking 11
+        let error = Day16::parse("000028400").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(1, 9, "sub-packets of 10 bits", "11 bits"))
+        );
+    }
+
+    /// `depth` sums with a single sub-packet each, around a literal.
+    fn nested_sums(depth: usize) -> String {
+        let mut bits = "000000100000000001".repeat(depth) + "00010000001";
+        while !bits.len().is_multiple_of(4) {
+            bits.push('0');
+        }
+        bits.as_bytes()
+            .chunks(4)
+            .map(|nibble| {
+                let nibble = std::str::from_utf8(nibble).unwrap();
+                format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_nesting() {
+        let packet = Day16::parse(&nested_sums(MAX_DEPTH)).unwrap();
+        assert_eq!(Day16::part2(&packet), Ok(1));
+
+        let error = Day16::parse(&nested_sums(MAX_DEPTH + 1)).err();
+        let column = (MAX_DEPTH * 18 + 6) / 4 + 1;
+        assert_eq!(
+            error,
+            Some(ParseError::new(
+                1,
+                column,
+                "at most 250 nested operators",
+                "another operator"
+            ))
+        );
+
+        assert!(Day16::parse(&nested_sums(200_000)).is_err());
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let literal = (0..8u32, any::<u64>()).prop_map(|(version, literal)| {
+            Packet::Literal(Literal {
+                version,
+                type_id: 4,
+                literal,
+            })
+        });
+
+        literal.prop_recursive(4, 32, 4, |inner| {
+            let operator = |type_ids: Vec<u32>, subpackets| {
+                (0..8u32, prop::sample::select(type_ids), subpackets).prop_map(
+                    |(version, type_id, subpackets)| {
+                        Packet::Operation(Operation {
+                            version,
+                            type_id,
+                            subpackets,
+                        })
+                    },
+                )
+            };
+
+            prop_oneof![
+                operator(vec![0, 1, 2, 3], prop::collection::vec(inner.clone(), 1..5)),
+                operator(vec![5, 6, 7], prop::collection::vec(inner, 2)),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_encode_round_trip(packet in packet()) {
+            let decoded = parse_packet(&mut parse_input(&encode(&packet).unwrap()).unwrap(), 0);
+            prop_assert_eq!(decoded, Ok(packet));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::ops::RangeInclusive;
+
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = (RangeInclusive<i32>, RangeInclusive<i32>);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((target_x, target_y): &Self::Input) -> Self::Part1 {
+        solve(target_x.clone(), target_y.clone()).0
+    }
+
+    fn part2((target_x, target_y): &Self::Input) -> Self::Part2 {
+        solve(target_x.clone(), target_y.clone()).1
+    }
+}
+
+fn parse_input(input: &str) -> Result<(RangeInclusive<i32>, RangeInclusive<i32>), ParseError> {
+    let line = input.trim();
+    let area = line
+        .strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::at(1, line, line, "'target area: '"))?;
+    let (x, y) = area
+        .split_once(", ")
+        .ok_or_else(|| ParseError::end_of_line(1, line, "', '"))?;
+
+    Ok((parse_range(line, x, "x=")?, parse_range(line, y, "y=")?))
+}
+
+fn parse_range(line: &str, range: &str, prefix: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let bounds = range
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(1, line, range, format!("'{}'", prefix)))?;
+    let (start, end) = bounds
+        .split_once("..")
+        .ok_or_else(|| ParseError::at(1, line, bounds, "start..end"))?;
+
+    Ok(parse::number(1, line, start)?..=parse::number(1, line, end)?)
+}
+
+/// Highest point reached and number of initial velocities that hit the target, sweeping x
+/// velocities 1 to 199 and y velocities -200 to 199.
+fn solve(target_x: RangeInclusive<i32>, target_y: RangeInclusive<i32>) -> (i32, i32) {
+    #[cfg(not(feature = "parallel"))]
+    let (max_height, hits) = (1..200)
+        .flat_map(|x| (-200..200).map(move |y| (x, y)))
+        .filter_map(|velocity| eval(velocity, target_x.clone(), target_y.clone()))
+        .fold((0, 0), |(max, hits), height| (max.max(height), hits + 1));
+
+    #[cfg(feature = "parallel")]
+    let (max_height, hits) = {
+        let heights = (1..200)
+            .into_par_iter()
+            .flat_map_iter(|x| (-200..200).map(move |y| (x, y)))
+            .filter_map(|velocity| eval(velocity, target_x.clone(), target_y.clone()))
+            .collect::<Vec<_>>();
+        (
+            heights.iter().fold(0, |max, height| max.max(*height)),
+            heights.len() as i32,
+        )
+    };
+
+    tracing::debug!(velocities = 199 * 400, hits);
+    (max_height, hits)
+}
+
+/// Highest point reached on the way to the target, or `None` if the probe misses it. A probe
+/// that would fly past the range of `i32` is a miss.
+fn eval(
+    mut velocity: (i32, i32),
+    target_x: RangeInclusive<i32>,
+    target_y: RangeInclusive<i32>,
+) -> Option<i32> {
+    let mut position: (i32, i32) = (0, 0);
+    let mut max_y = 0;
+
+    loop {
+        position.0 = position.0.checked_add(velocity.0)?;
+        position.1 = position.1.checked_add(velocity.1)?;
+        max_y = max_y.max(position.1);
+        velocity.0 = velocity.0 - velocity.0.signum();
+        velocity.1 -= 1;
+
+        if target_x.contains(&position.0) && target_y.contains(&position.1) {
+            return Some(max_y);
+        }
+
+        if position.0 > *target_x.end() || position.1 < *target_y.start() {
+            return None;
+        }
+
+        // Without horizontal speed the probe falls straight down beside the target
+        if velocity.0 == 0 && !target_x.contains(&position.0) {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse::ParseError, Solution};
+
+    use crate::{parse_input, Day17};
+
+    #[test]
+    fn test_part1() {
+        let input = Day17::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day17::part1(&input), 45);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day17::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day17::part2(&input), 112);
+    }
+
+    #[test]
+    fn test_deep_target() {
+        let input = Day17::parse("target area: x=1000..2000, y=-2147483648..-5").unwrap();
+        assert_eq!(Day17::part1(&input), 19900);
+        assert_eq!(Day17::part2(&input), 35872);
+
+        let input = Day17::parse("target area: x=1..1, y=-2147483648..-2147483648").unwrap();
+        assert_eq!(Day17::part2(&input), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("target area: x=20..30, y=-10..-x").err();
+        assert_eq!(error, Some(ParseError::new(1, 31, "number", "-x")));
+
+        let error = parse_input("target area: x=20..30, z=-10..-5").err();
+        assert_eq!(error, Some(ParseError::new(1, 24, "'y='", "z=-10..-5")));
+
+        let error = parse_input("target area: x=20..30").err();
+        assert_eq!(error, Some(ParseError::new(1, 22, "', '", "")));
+    }
+}
@@ -0,0 +1,514 @@
+use std::convert::TryInto;
+
+use common::{parse::ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tinyjson::JsonValue;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Tree>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let trees = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| parse_tree(i + 1, line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if trees.is_empty() {
+            return Err(ParseError::new(1, 1, "snailfish number", ""));
+        }
+        Ok(trees)
+    }
+
+    fn part1(trees: &Self::Input) -> Self::Part1 {
+        part1(trees.clone())
+    }
+
+    fn part2(trees: &Self::Input) -> Self::Part2 {
+        part2(trees.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+impl Tree {
+    fn new() -> Self {
+        Self {
+            nodes: Default::default(),
+            root: 0,
+        }
+    }
+
+    fn insert(&mut self, node: Node) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn set_root(&mut self, new_root: usize) {
+        self.root = new_root;
+    }
+
+    fn set_parent(&mut self, node_id: usize, parent: usize) {
+        if let Some(node) = self.nodes.get_mut(node_id) {
+            node.parent = Some(parent);
+        }
+    }
+
+    fn traverse_in_order(&self) -> impl Iterator<Item = (Node, usize, usize)> {
+        self.in_order(None).into_iter()
+    }
+
+    fn in_order(&self, node_id: Option<usize>) -> Vec<(Node, usize, usize)> {
+        let node_id = node_id.unwrap_or(self.root);
+        let mut buffer = vec![];
+        self.in_order_rec(node_id, 0, &mut buffer);
+        buffer
+    }
+
+    fn in_order_rec(&self, node_id: usize, depth: usize, buffer: &mut Vec<(Node, usize, usize)>) {
+        if let Some(node) = self.nodes.get(node_id) {
+            match node.value {
+                Value::Number(_) => buffer.push((node.clone(), depth, node_id)),
+                Value::Branch(left, right) => {
+                    self.in_order_rec(left, depth + 1, buffer);
+                    buffer.push((node.clone(), depth, node_id));
+                    self.in_order_rec(right, depth + 1, buffer);
+                }
+            }
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    parent: Option<usize>,
+    value: Value,
+}
+
+impl Node {
+    fn new(value: Value, parent: Option<usize>) -> Self {
+        Self { value, parent }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Number(f64),
+    Branch(usize, usize),
+}
+
+impl Value {
+    fn number(&self) -> Option<f64> {
+        match self {
+            Value::Number(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    fn children(&self) -> Option<(usize, usize)> {
+        match self {
+            Value::Branch(left, right) => Some((*left, *right)),
+            _ => None,
+        }
+    }
+
+    fn set_number(&mut self, value: f64) {
+        if let Value::Number(x) = self {
+            *x = value;
+        }
+    }
+}
+
+fn part1(trees: Vec<Tree>) -> usize {
+    let n = trees.len();
+    let acc = {
+        let mut acc = trees.first().cloned().unwrap();
+        for tree in trees.into_iter().skip(1) {
+            let new_tree = merge_trees(acc.clone(), tree);
+            acc = reduce(new_tree);
+        }
+        acc
+    };
+    tracing::debug!(additions = n - 1);
+    magnitude(&acc, None)
+}
+
+fn part2(trees: Vec<Tree>) -> usize {
+    let n = trees.len();
+
+    // Every ordered pair (x, y) of different numbers, as x * n + y
+    #[cfg(not(feature = "parallel"))]
+    let pairs = 0..n * n;
+    #[cfg(feature = "parallel")]
+    let pairs = (0..n * n).into_par_iter();
+
+    tracing::debug!(additions = n * (n - 1));
+    pairs
+        .filter(|i| i / n != i % n)
+        .map(|i| {
+            let merged = merge_trees(trees[i / n].clone(), trees[i % n].clone());
+            magnitude(&reduce(merged), None)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn merge_trees(left: Tree, right: Tree) -> Tree {
+    let json = format!(
+        "[{},{}]",
+        tree_to_string(&left, None),
+        tree_to_string(&right, None)
+    );
+    let json = json.parse::<JsonValue>().unwrap();
+    let mut tree = Tree::new();
+    json_to_tree(&mut tree, None, json).expect("merged trees are well formed");
+
+    tree
+}
+
+fn tree_to_string(tree: &Tree, node_id: Option<usize>) -> String {
+    if let Some(node) = tree.nodes.get(node_id.unwrap_or(tree.root)) {
+        match node.value {
+            Value::Branch(left, right) => {
+                let s1 = tree_to_string(tree, Some(left));
+                let s2 = tree_to_string(tree, Some(right));
+                format!("[{},{}]", s1, s2)
+            }
+            Value::Number(n) => n.to_string(),
+        }
+    } else {
+        unreachable!()
+    }
+}
+
+fn magnitude(acc: &Tree, node_id: Option<usize>) -> usize {
+    let node_id = node_id.unwrap_or(acc.root);
+    if let Some(node) = acc.nodes.get(node_id) {
+        match node.value {
+            Value::Number(n) => n as usize,
+            Value::Branch(left, right) => {
+                magnitude(acc, Some(left)) * 3 + magnitude(acc, Some(right)) * 2
+            }
+        }
+    } else {
+        unreachable!()
+    }
+}
+
+/// Deepest a pair may be nested inside others in a reduced snailfish number
+const MAX_NESTING: usize = 3;
+
+/// Parses a homework line, which has to be a reduced snailfish number.
+fn parse_tree(line_number: usize, line: &str) -> Result<Tree, ParseError> {
+    let raw = line.trim();
+    let error = |expected| ParseError::at(line_number, line, raw, expected);
+
+    // Checked before parsing, which recurses once per bracket
+    let mut brackets = 0usize;
+    for c in raw.chars() {
+        match c {
+            '[' => brackets += 1,
+            ']' => brackets = brackets.saturating_sub(1),
+            _ => {}
+        }
+        if brackets > MAX_NESTING + 1 {
+            return Err(error("pairs nested at most 4 deep"));
+        }
+    }
+
+    let json = raw
+        .parse::<JsonValue>()
+        .map_err(|_| error("snailfish number"))?;
+    let mut tree = Tree::new();
+    json_to_tree(&mut tree, None, json).map_err(error)?;
+
+    for (node, depth, _) in tree.traverse_in_order() {
+        match node.value {
+            Value::Branch(_, _) if depth > MAX_NESTING => {
+                return Err(error("pairs nested at most 4 deep"))
+            }
+            Value::Number(n) if n.fract() != 0.0 || !(0.0..10.0).contains(&n) => {
+                return Err(error("regular numbers 0-9"))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Inserts `json` into `tree`, returning the id of its node or what it should have been instead.
+fn json_to_tree(
+    tree: &mut Tree,
+    parent: Option<usize>,
+    json: JsonValue,
+) -> Result<usize, &'static str> {
+    match json {
+        JsonValue::Array(array) => {
+            let [left, right]: [JsonValue; 2] = array.try_into().map_err(|_| "pair")?;
+            let left = json_to_tree(tree, None, left)?;
+            let right = json_to_tree(tree, None, right)?;
+            let parent = tree.insert(Node::new(Value::Branch(left, right), parent));
+            tree.set_parent(left, parent);
+            tree.set_parent(right, parent);
+            tree.set_root(parent);
+            Ok(parent)
+        }
+        JsonValue::Number(n) => {
+            let node = Node::new(Value::Number(n), parent);
+            Ok(tree.insert(node))
+        }
+        _ => Err("pair or regular number"),
+    }
+}
+
+fn reduce(mut tree: Tree) -> Tree {
+    let (mut explosions, mut splits) = (0usize, 0usize);
+
+    loop {
+        let (exploded, data) = explode(tree);
+        if exploded {
+            explosions += 1;
+            tree = data;
+            continue;
+        }
+
+        let (was_split, data) = split(data);
+        tree = data;
+        if !was_split {
+            break;
+        }
+        splits += 1;
+    }
+
+    // Part 2 reduces thousands of sums, so these only show up on trace level
+    tracing::trace!(explosions, splits, "reduced");
+    tree
+}
+
+fn explode(mut tree: Tree) -> (bool, Tree) {
+    let explodable = find_first_nested_node(&tree);
+
+    if let Some(node_idx) = explodable {
+        let (left_idx, right_idx) = tree.nodes.get(node_idx).unwrap().value.children().unwrap();
+        let left_value = tree.nodes.get(left_idx).unwrap().value.number().unwrap();
+        let right_value = tree.nodes.get(right_idx).unwrap().value.number().unwrap();
+
+        if let Some(left_neighbour) = find_neighbour_on_left_side(&tree, left_idx) {
+            if let Some(left_node) = tree.nodes.get_mut(left_neighbour) {
+                left_node
+                    .value
+                    .set_number(left_node.value.number().unwrap() + left_value);
+            }
+        }
+        if let Some(right_neighbour) = find_neighbour_on_right_side(&tree, right_idx) {
+            if let Some(right_node) = tree.nodes.get_mut(right_neighbour) {
+                right_node
+                    .value
+                    .set_number(right_node.value.number().unwrap() + right_value);
+            }
+        }
+
+        let exploded_node = tree.nodes.get_mut(node_idx).unwrap();
+        exploded_node.value = Value::Number(0f64);
+
+        return (true, tree);
+    }
+
+    (false, tree)
+}
+
+fn find_first_nested_node(tree: &Tree) -> Option<usize> {
+    for (node, depth, node_id) in tree.traverse_in_order() {
+        if let Value::Branch(l, r) = node.value {
+            if depth >= 4 {
+                let left = tree.nodes.get(l).unwrap();
+                let right = tree.nodes.get(r).unwrap();
+                if let (Value::Number(_), Value::Number(_)) = (&left.value, &right.value) {
+                    return Some(node_id);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_neighbour_on_left_side(tree: &Tree, node_id: usize) -> Option<usize> {
+    let mut last_node_idx = None;
+    for (node, _, idx) in tree.traverse_in_order() {
+        if idx == node_id {
+            break;
+        } else if let Value::Number(_) = node.value {
+            last_node_idx = Some(idx)
+        }
+    }
+    last_node_idx
+}
+
+fn find_neighbour_on_right_side(tree: &Tree, node_id: usize) -> Option<usize> {
+    let mut passed = false;
+    for (node, _, idx) in tree.traverse_in_order() {
+        if passed {
+            if let Value::Number(_) = node.value {
+                return Some(idx);
+            }
+        }
+
+        if idx == node_id {
+            passed = true;
+        }
+    }
+    None
+}
+
+fn split(mut tree: Tree) -> (bool, Tree) {
+    for (node, _, node_id) in tree.traverse_in_order() {
+        if let Value::Number(n) = node.value {
+            if n >= 10f64 {
+                let left = Node::new(Value::Number(f64::floor(n / 2f64)), Some(node_id));
+                let right = Node::new(Value::Number(f64::ceil(n / 2f64)), Some(node_id));
+
+                let left_id = tree.insert(left);
+                let right_id = tree.insert(right);
+
+                let self_node = tree.nodes.get_mut(node_id).unwrap();
+                self_node.value = Value::Branch(left_id, right_id);
+
+                return (true, tree);
+            }
+        }
+    }
+
+    (false, tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use tinyjson::JsonValue;
+
+    use common::{parse::ParseError, Solution};
+
+    use crate::{
+        explode, json_to_tree, merge_trees, parse_tree, reduce, split, tree_to_string, Day18, Tree,
+        Value,
+    };
+
+    #[test]
+    fn test_explode() {
+        let tree = "[[[[[9,8],1],2],3],4]"
+            .lines()
+            .next()
+            .map(|line| line.parse::<JsonValue>().unwrap())
+            .map(|json| {
+                let mut tree = Tree::new();
+                json_to_tree(&mut tree, None, json).unwrap();
+                tree
+            })
+            .unwrap();
+        let (exploded, new_tree) = explode(tree);
+        assert!(exploded);
+        assert_eq!(
+            "[[[[0,9],2],3],4]".to_string(),
+            tree_to_string(&new_tree, None)
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let tree = "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]"
+            .lines()
+            .next()
+            .map(|line| line.parse::<JsonValue>().unwrap())
+            .map(|json| {
+                let mut tree = Tree::new();
+                json_to_tree(&mut tree, None, json).unwrap();
+                tree
+            })
+            .unwrap();
+        let (split, new_tree) = split(tree);
+        assert!(split);
+        assert_eq!(
+            "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]".to_string(),
+            tree_to_string(&new_tree, None)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day18::parse("[1,2]\n[[1,2],3").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(2, 1, "snailfish number", "[[1,2],3"))
+        );
+
+        let error = Day18::parse("[1,[2,3,4]]").err();
+        assert_eq!(error, Some(ParseError::new(1, 1, "pair", "[1,[2,3,4]]")));
+
+        let error = Day18::parse("[[[[[1,2],3],4],5],6]").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(
+                1,
+                1,
+                "pairs nested at most 4 deep",
+                "[[[[[1,2],3],4],5],6]"
+            ))
+        );
+
+        let deep = "[".repeat(100_000);
+        let error = Day18::parse(&deep).err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(1, 1, "pairs nested at most 4 deep", &deep))
+        );
+
+        let error = Day18::parse("[1,10]").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(1, 1, "regular numbers 0-9", "[1,10]"))
+        );
+
+        assert!(Day18::parse("").is_err());
+    }
+
+    /// A reduced snailfish number, as found in the homework.
+    fn snailfish_number() -> impl Strategy<Value = String> {
+        let leaf = (0..10u32).prop_map(|n| n.to_string());
+        let pair = leaf.prop_recursive(3, 16, 2, |inner| {
+            (inner.clone(), inner).prop_map(|(left, right)| format!("[{},{}]", left, right))
+        });
+        (pair.clone(), pair).prop_map(|(left, right)| format!("[{},{}]", left, right))
+    }
+
+    proptest! {
+        #[test]
+        fn test_reduce_sum(left in snailfish_number(), right in snailfish_number()) {
+            let sum = reduce(merge_trees(
+                parse_tree(1, &left).unwrap(),
+                parse_tree(1, &right).unwrap(),
+            ));
+
+            for (node, depth, _) in sum.traverse_in_order() {
+                match node.value {
+                    Value::Branch(_, _) => prop_assert!(depth < 4),
+                    Value::Number(n) => prop_assert!(n < 10.0),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    parse::{self, ParseError},
+    NoAnswer, Solution,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Vec<Scanner>;
+    type Part1 = Result<usize, NoAnswer>;
+    type Part2 = Result<i64, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(scanners: &Self::Input) -> Self::Part1 {
+        solve(scanners).map(|merged| merged.beacons.len())
+    }
+
+    fn part2(scanners: &Self::Input) -> Self::Part2 {
+        solve(scanners).map(|merged| merged.max_distance())
+    }
+}
+
+/// Beacon coordinates are read as `i32` but stored wider, so the offsets between scanners at
+/// opposite ends of that range fit.
+pub type Point3 = [i64; 3];
+
+/// Beacons two scanners need to have in common to be considered overlapping.
+const MIN_OVERLAP: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    pub id: usize,
+    pub beacons: Vec<Point3>,
+}
+
+/// One of the 24 ways a scanner can face, as a signed permutation of the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    /// All proper rotations, i.e. the signed axis permutations with determinant 1.
+    pub fn all() -> Vec<Rotation> {
+        const PERMUTATIONS: [([usize; 3], i64); 6] = [
+            ([0, 1, 2], 1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([0, 2, 1], -1),
+            ([1, 0, 2], -1),
+            ([2, 1, 0], -1),
+        ];
+
+        let mut rotations = vec![];
+        for (axes, parity) in PERMUTATIONS {
+            for signs in 0..8 {
+                let signs = [0, 1, 2].map(|bit| if signs & (1 << bit) == 0 { 1 } else { -1 });
+                if parity * signs.iter().product::<i64>() == 1 {
+                    rotations.push(Rotation { axes, signs });
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn apply(&self, point: Point3) -> Point3 {
+        [0, 1, 2].map(|i| self.signs[i] * point[self.axes[i]])
+    }
+}
+
+/// Where a scanner sits relative to scanner 0: its reports are turned into scanner 0's frame
+/// by rotating them and adding `translation`, which is the scanner's own position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub rotation: Rotation,
+    pub translation: Point3,
+}
+
+impl Placement {
+    pub fn apply(&self, point: Point3) -> Point3 {
+        add(self.rotation.apply(point), self.translation)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Merged {
+    /// Placement of every scanner, indexed like the input
+    pub placements: Vec<Placement>,
+    /// Every beacon in scanner 0's frame
+    pub beacons: HashSet<Point3>,
+}
+
+impl Merged {
+    pub fn max_distance(&self) -> i64 {
+        let positions = self
+            .placements
+            .iter()
+            .map(|placement| placement.translation)
+            .collect::<Vec<_>>();
+
+        positions
+            .iter()
+            .flat_map(|a| positions.iter().map(move |b| manhattan(*a, *b)))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub fn solve(scanners: &[Scanner]) -> Result<Merged, NoAnswer> {
+    merge(scanners).ok_or(NoAnswer("some scanner does not overlap with the others"))
+}
+
+/// Places every scanner relative to the first one, or `None` if some scanner cannot be
+/// connected to it through overlapping beacons.
+pub fn merge(scanners: &[Scanner]) -> Option<Merged> {
+    let rotations = Rotation::all();
+    let mut placements: Vec<Option<Placement>> = vec![None; scanners.len()];
+    // Beacons of placed scanners in scanner 0's frame
+    let mut placed: Vec<Vec<Point3>> = vec![vec![]; scanners.len()];
+
+    let first = scanners.first()?;
+    placements[0] = Some(Placement {
+        rotation: rotations[0],
+        translation: [0, 0, 0],
+    });
+    placed[0] = first.beacons.clone();
+
+    let mut queue = vec![0];
+    let mut attempts = 0;
+    while let Some(reference) = queue.pop() {
+        for (i, scanner) in scanners.iter().enumerate() {
+            if placements[i].is_some() {
+                continue;
+            }
+            attempts += 1;
+
+            if let Some(placement) = find_placement(&placed[reference], scanner, &rotations) {
+                placements[i] = Some(placement);
+                placed[i] = scanner
+                    .beacons
+                    .iter()
+                    .map(|beacon| placement.apply(*beacon))
+                    .collect();
+                queue.push(i);
+            }
+        }
+    }
+
+    tracing::debug!(
+        attempts,
+        placed = placements.iter().flatten().count(),
+        scanners = scanners.len()
+    );
+    let placements = placements.into_iter().collect::<Option<Vec<_>>>()?;
+    let beacons = placed.into_iter().flatten().collect();
+
+    Some(Merged {
+        placements,
+        beacons,
+    })
+}
+
+/// Tries every rotation of `scanner` and looks for a translation that lines up at least
+/// [`MIN_OVERLAP`] of its beacons with the already placed `reference` beacons.
+fn find_placement(
+    reference: &[Point3],
+    scanner: &Scanner,
+    rotations: &[Rotation],
+) -> Option<Placement> {
+    for rotation in rotations {
+        let rotated = scanner
+            .beacons
+            .iter()
+            .map(|beacon| rotation.apply(*beacon))
+            .collect::<Vec<_>>();
+
+        let mut offsets: HashMap<Point3, usize> = HashMap::new();
+        for a in reference {
+            for b in &rotated {
+                let count = offsets.entry(sub(*a, *b)).or_default();
+                *count += 1;
+                if *count >= MIN_OVERLAP {
+                    return Some(Placement {
+                        rotation: *rotation,
+                        translation: sub(*a, *b),
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn add(a: Point3, b: Point3) -> Point3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Point3, b: Point3) -> Point3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn manhattan(a: Point3, b: Point3) -> i64 {
+    sub(a, b).iter().map(|d| d.abs()).sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    let scanners = parse::blocks(input)
+        .into_iter()
+        .map(|(start, block)| parse_scanner(block).map_err(|error| error.shift_lines(start)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if scanners.is_empty() {
+        return Err(ParseError::new(1, 1, "'--- scanner N ---'", ""));
+    }
+    Ok(scanners)
+}
+
+fn parse_scanner(block: &str) -> Result<Scanner, ParseError> {
+    let mut lines = block.lines();
+    let header = lines.next().unwrap_or_default();
+    let id = header
+        .trim()
+        .strip_prefix("--- scanner ")
+        .and_then(|rest| rest.strip_suffix(" ---"))
+        .ok_or_else(|| ParseError::at(1, header, header.trim(), "'--- scanner N ---'"))?;
+    let id = parse::number(1, header, id)?;
+
+    let beacons = lines
+        .enumerate()
+        .map(|(i, line)| parse_beacon(i + 2, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Scanner { id, beacons })
+}
+
+fn parse_beacon(line_number: usize, line: &str) -> Result<Point3, ParseError> {
+    let mut coordinates = line.trim().split(',');
+    let mut beacon = [0; 3];
+    for coordinate in beacon.iter_mut() {
+        let token = coordinates
+            .next()
+            .ok_or_else(|| ParseError::end_of_line(line_number, line, "x,y,z"))?;
+        *coordinate = parse::number::<i32>(line_number, line, token)?.into();
+    }
+
+    match coordinates.next() {
+        Some(extra) => Err(ParseError::at(line_number, line, extra, "end of line")),
+        None => Ok(beacon),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use common::{parse::ParseError, NoAnswer};
+
+    use crate::{parse_input, solve, Rotation};
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+
+        let images = rotations
+            .iter()
+            .map(|rotation| rotation.apply([1, 2, 3]))
+            .collect::<HashSet<_>>();
+        assert_eq!(images.len(), 24);
+    }
+
+    #[test]
+    fn test_example() {
+        let scanners = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(scanners.len(), 5);
+
+        let merged = solve(&scanners).unwrap();
+        assert_eq!(merged.beacons.len(), 79);
+        assert_eq!(merged.max_distance(), 3621);
+
+        let positions = merged
+            .placements
+            .iter()
+            .map(|placement| placement.translation)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                [0, 0, 0],
+                [68, -1246, -43],
+                [1105, -1205, 1229],
+                [-92, -2380, -20],
+                [-20, -1133, 1061]
+            ]
+        );
+
+        // Scanner 4 only overlaps with scanner 1
+        assert_eq!(
+            solve(&[scanners[0].clone(), scanners[4].clone()]).err(),
+            Some(NoAnswer("some scanner does not overlap with the others"))
+        );
+    }
+
+    #[test]
+    fn test_far_apart() {
+        let scanners =
+            parse_input("--- scanner 0 ---\n2147483647,0,0\n\n--- scanner 1 ---\n-5,0,0\n");
+        assert_eq!(
+            solve(&scanners.unwrap()).err(),
+            Some(NoAnswer("some scanner does not overlap with the others"))
+        );
+
+        let beacons = |x: i64| {
+            (0..12)
+                .map(|k: i64| format!("{},{},{}\n", x - k, k * k, k * k * k))
+                .collect::<String>()
+        };
+        let input = format!(
+            "--- scanner 0 ---\n{}\n--- scanner 1 ---\n{}",
+            beacons(i32::MAX.into()),
+            beacons(i64::from(i32::MIN) + 11)
+        );
+        let merged = solve(&parse_input(&input).unwrap()).unwrap();
+        assert_eq!(merged.beacons.len(), 12);
+        assert_eq!(merged.max_distance(), 4294967284);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("\n\n").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(1, 1, "'--- scanner N ---'", ""))
+        );
+
+        let error = parse_input("--- scanner 0 ---\n1,2,3\n1,2\n").err();
+        assert_eq!(error, Some(ParseError::new(3, 4, "x,y,z", "")));
+
+        let error = parse_input("--- scanner 0 ---\n1,2,3\n\n--- probe 1 ---\n").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(
+                4,
+                1,
+                "'--- scanner N ---'",
+                "--- probe 1 ---"
+            ))
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use common::{input, trace, Part, Solution};
+use day_19::Day19;
+
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day19::DAY, env!("CARGO_MANIFEST_DIR"));
+    let scanners = common::parse_or_exit::<Day19>(&input);
+
+    let part1 = common::answer_or_exit::<Day19>(Part::One, trace::part1::<Day19>(&scanners));
+    println!("Part 1: {}", part1);
+
+    let part2 = common::answer_or_exit::<Day19>(Part::Two, trace::part2::<Day19>(&scanners));
+    println!("Part 2: {}", part2);
+}
@@ -0,0 +1,206 @@
+use animation::{Animation, Color, Styled};
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use grid::Grid;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = (Vec<char>, Map);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((program, map): &Self::Input) -> Self::Part1 {
+        solve(program, map.clone(), 2)
+    }
+
+    fn part2((program, map): &Self::Input) -> Self::Part2 {
+        solve(program, map.clone(), 50)
+    }
+}
+
+fn solve(program: &[char], mut map: Map, iterations: usize) -> usize {
+    for _ in 0..iterations {
+        map.tick(program);
+    }
+
+    let lit = map.lit();
+    tracing::debug!(iterations, lit);
+    lit
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Map), ParseError> {
+    let blocks = parse::blocks(input);
+    let (_, raw_program) = blocks
+        .first()
+        .ok_or_else(|| ParseError::new(1, 1, "enhancement program", ""))?;
+    let (map_start, raw_map) = blocks.get(1).ok_or_else(|| {
+        let line = raw_program.lines().count() + 2;
+        ParseError::new(line, 1, "image", "")
+    })?;
+
+    let program = raw_program.chars().collect::<Vec<_>>();
+    if let Some((column, c)) = program
+        .iter()
+        .enumerate()
+        .find(|(_, c)| **c != '#' && **c != '.')
+    {
+        return Err(ParseError::new(1, column + 1, "'#' or '.'", *c));
+    }
+    if program.len() < 512 {
+        return Err(ParseError::end_of_line(1, raw_program, "512 pixels"));
+    }
+    if program.len() > 512 {
+        return Err(ParseError::new(1, 513, "end of line", program[512]));
+    }
+
+    let inner = Grid::parse_with(raw_map, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .map_err(|error| error.shift_lines(*map_start))?;
+
+    Ok((program, Map::new(inner)))
+}
+
+#[derive(Clone)]
+pub struct Map {
+    inner: Grid<bool>,
+    // State of the infinite area outside of `inner`
+    background: bool,
+}
+
+impl Map {
+    fn new(inner: Grid<bool>) -> Self {
+        Self {
+            inner,
+            background: false,
+        }
+    }
+
+    fn lit(&self) -> usize {
+        self.inner.values().filter(|v| **v).count()
+    }
+
+    fn tick(&mut self, program: &[char]) {
+        // The image grows by one pixel on every side, the new (x, y) being (x - 1, y - 1) before
+        let mut next = Grid::new(self.inner.width() + 2, self.inner.height() + 2, false);
+        for ((x, y), pixel) in next.iter_mut() {
+            let convolution = self.convolute((x as isize - 1, y as isize - 1));
+            *pixel = program[convolution] == '#';
+        }
+        self.inner = next;
+
+        // The background is either all dark (index 0) or all lit (index 511)
+        let background_index = if self.background { 511 } else { 0 };
+        self.background = program[background_index] == '#';
+    }
+
+    fn convolute(&self, (x, y): (isize, isize)) -> usize {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (0, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ]
+        .iter()
+        .map(|(dx, dy)| {
+            self.inner
+                .get_signed((x + dx, y + dy))
+                .copied()
+                .unwrap_or(self.background)
+        })
+        .fold(0, |acc, bit| acc << 1 | bit as usize)
+    }
+}
+
+impl std::fmt::Debug for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        write!(f, "{}", self.inner.map(|lit| if *lit { '#' } else { '.' }))
+    }
+}
+
+/// Replays the image enhancement, growing by a pixel on every side per step.
+pub struct Enhancement {
+    program: Vec<char>,
+    map: Map,
+    step: usize,
+    steps: usize,
+}
+
+pub fn animation((program, map): &(Vec<char>, Map), steps: usize) -> Enhancement {
+    Enhancement {
+        program: program.clone(),
+        map: map.clone(),
+        step: 0,
+        steps,
+    }
+}
+
+impl Animation for Enhancement {
+    fn step(&mut self) -> bool {
+        self.map.tick(&self.program);
+        self.step += 1;
+        self.step < self.steps
+    }
+
+    fn frame(&self) -> Grid<Styled> {
+        self.map.inner.map(|lit| {
+            if *lit {
+                Styled::colored('#', Color::Cyan)
+            } else {
+                Styled::colored('.', Color::Gray)
+            }
+        })
+    }
+
+    fn caption(&self) -> String {
+        format!("step {}/{}: {} lit", self.step, self.steps, self.map.lit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, solve};
+
+    #[test]
+    fn test_convolution() {
+        let (_, map) = parse_input(include_str!("test.input.txt")).unwrap();
+        let convolution = map.convolute((2, 2));
+        assert_eq!(convolution, 34);
+
+        let convolution = map.convolute((3, 1));
+        assert_eq!(
+            convolution,
+            usize::from_str_radix("010000001", 2).unwrap() // 129
+        );
+
+        let convolution = map.convolute((4, 1));
+        assert_eq!(
+            convolution,
+            usize::from_str_radix("100000010", 2).unwrap() // 258
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let (program, map) = parse_input(include_str!("test.input.txt")).unwrap();
+        let part1 = solve(&program, map, 2);
+        assert_eq!(part1, 35);
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = (usize, usize);
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+        let mut position = || match lines.next() {
+            Some((i, line)) => parse_position(i + 1, line),
+            None => Err(ParseError::new(
+                input.lines().count() + 1,
+                1,
+                "starting position",
+                "",
+            )),
+        };
+        Ok((position()?, position()?))
+    }
+
+    fn part1(&(p1, p2): &Self::Input) -> Self::Part1 {
+        solve_rec(p1, p2)
+    }
+
+    fn part2(&(p1, p2): &Self::Input) -> Self::Part2 {
+        solve_rec_2(p1, p2)
+    }
+}
+
+fn parse_position(line_number: usize, line: &str) -> Result<usize, ParseError> {
+    let position = line.trim().rsplit(' ').next().unwrap_or(line);
+    match parse::number(line_number, line, position)? {
+        position @ 1..=10 => Ok(position),
+        _ => Err(ParseError::at(line_number, line, position, "position 1-10")),
+    }
+}
+
+// (scores, positions, roll) -> (wins player 1, wins player 2)
+type Cache = HashMap<([usize; 2], [usize; 2], usize), (u64, u64)>;
+
+fn solve_rec_2(p1: usize, p2: usize) -> u64 {
+    let positions = [p1, p2];
+    let scores = [0, 0];
+    let mut dp = HashMap::new();
+
+    let (p1, p2) = solve_rec_do_2(&mut dp, scores, positions, 0);
+    tracing::debug!(states = dp.len(), wins = ?(p1, p2));
+    p1.max(p2)
+}
+
+fn solve_rec_do_2(
+    dp: &mut Cache,
+    scores: [usize; 2],
+    positions: [usize; 2],
+    roll: usize,
+) -> (u64, u64) {
+    if let Some(stored) = dp.get(&(scores, positions, roll)) {
+        return *stored;
+    }
+
+    if scores[0] >= 21 {
+        return (1, 0);
+    }
+    if scores[1] >= 21 {
+        return (0, 1);
+    }
+
+    let mut outcomes = (0, 0);
+    for x in 1..=3 {
+        for y in 1..=3 {
+            for z in 1..=3 {
+                let mut scores = scores;
+                let mut positions = positions;
+                let steps = x + y + z;
+                let pid = roll % 2;
+                positions[pid] = (positions[pid] + steps) % 10;
+                if positions[pid] == 0 {
+                    positions[pid] = 10;
+                }
+                scores[pid] += positions[pid];
+
+                let outcome = solve_rec_do_2(dp, scores, positions, roll + 1);
+                dp.insert((scores, positions, roll + 1), outcome);
+
+                outcomes = (outcomes.0 + outcome.0, outcomes.1 + outcome.1);
+            }
+        }
+    }
+
+    outcomes
+}
+
+fn solve_rec(p1: usize, p2: usize) -> usize {
+    let die = &mut (1..=100usize).cycle();
+    let positions = [p1, p2];
+    let scores = [0, 0];
+
+    let (score, _) = solve_rec_do(scores, positions, 1, die);
+
+    score
+}
+
+fn solve_rec_do(
+    mut scores: [usize; 2],
+    mut positions: [usize; 2],
+    roll: usize,
+    die: &mut impl Iterator<Item = usize>,
+) -> (usize, bool) {
+    let steps = die.take(3).sum::<usize>();
+    let pid = (roll - 1) % 2;
+    positions[pid] = (positions[pid] + steps) % 10;
+    if positions[pid] == 0 {
+        positions[pid] = 10;
+    }
+    scores[pid] += positions[pid];
+
+    if scores[0] >= 1000 || scores[1] >= 1000 {
+        tracing::debug!(turns = roll, rolls = 3 * roll, "game won");
+    }
+    if scores[0] >= 1000 {
+        return (scores[1] * 3 * roll, true);
+    } else if scores[1] >= 1000 {
+        return (scores[0] * 3 * roll, false);
+    }
+
+    solve_rec_do(scores, positions, roll + 1, die)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::Day21;
+
+    #[test]
+    fn test_part1() {
+        let input = Day21::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day21::part1(&input), 739785);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day21::parse(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day21::part2(&input), 444356092776315);
+    }
+}
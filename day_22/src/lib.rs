@@ -0,0 +1,249 @@
+use std::{convert::TryFrom, ops::RangeInclusive};
+
+use common::{
+    checked::Overflow,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<Step>;
+    type Part1 = Result<i64, Overflow>;
+    type Part2 = Result<i64, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Input) -> Self::Part1 {
+        let region = Cuboid {
+            x: -50..=50,
+            y: -50..=50,
+            z: -50..=50,
+        };
+        let steps = steps
+            .iter()
+            .filter_map(|step| {
+                Some(Step {
+                    on: step.on,
+                    cuboid: step.cuboid.intersection(&region)?,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        reboot(&steps)
+    }
+
+    fn part2(steps: &Self::Input) -> Self::Part2 {
+        reboot(steps)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cuboid {
+    pub x: RangeInclusive<i64>,
+    pub y: RangeInclusive<i64>,
+    pub z: RangeInclusive<i64>,
+}
+
+impl Cuboid {
+    pub fn volume(&self) -> Result<i64, Overflow> {
+        [&self.x, &self.y, &self.z]
+            .iter()
+            .try_fold(1i64, |volume, range| {
+                range
+                    .end()
+                    .checked_sub(*range.start())?
+                    .checked_add(1)
+                    .and_then(|length| volume.checked_mul(length))
+            })
+            .ok_or(Overflow("cuboid volume"))
+    }
+
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        Some(Cuboid {
+            x: overlap(&self.x, &other.x)?,
+            y: overlap(&self.y, &other.y)?,
+            z: overlap(&self.z, &other.z)?,
+        })
+    }
+}
+
+fn overlap(a: &RangeInclusive<i64>, b: &RangeInclusive<i64>) -> Option<RangeInclusive<i64>> {
+    let start = *a.start().max(b.start());
+    let end = *a.end().min(b.end());
+    (start <= end).then_some(start..=end)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+/// Counts the cubes left on after all steps, by inclusion-exclusion: every new step cancels
+/// out its overlap with each signed cuboid seen so far, and `on` steps then add themselves.
+fn reboot(steps: &[Step]) -> Result<i64, Overflow> {
+    let mut cuboids: Vec<(Cuboid, i64)> = vec![];
+
+    for step in steps {
+        let overlaps = cuboids
+            .iter()
+            .filter_map(|(cuboid, sign)| Some((cuboid.intersection(&step.cuboid)?, -sign)))
+            .collect::<Vec<_>>();
+        cuboids.extend(overlaps);
+
+        if step.on {
+            cuboids.push((step.cuboid.clone(), 1));
+        }
+    }
+
+    tracing::debug!(steps = steps.len(), cuboids = cuboids.len());
+    // Partial sums can leave the range of `i64` before later terms bring them back, `i128`
+    // holds far more terms than fit in memory
+    let total = cuboids.iter().try_fold(0i128, |total, (cuboid, sign)| {
+        Ok(total + i128::from(cuboid.volume()?) * i128::from(*sign))
+    })?;
+    i64::try_from(total).map_err(|_| Overflow("cubes on"))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_step(i + 1, line))
+        .collect()
+}
+
+fn parse_step(line_number: usize, line: &str) -> Result<Step, ParseError> {
+    let content = line.trim();
+    let (on, ranges) = if let Some(ranges) = content.strip_prefix("on ") {
+        (true, ranges)
+    } else if let Some(ranges) = content.strip_prefix("off ") {
+        (false, ranges)
+    } else {
+        return Err(ParseError::at(line_number, line, content, "on or off"));
+    };
+
+    let mut ranges = ranges.split(',');
+    let mut axes = ["x=", "y=", "z="].iter().map(|&axis| {
+        let raw = ranges
+            .next()
+            .ok_or_else(|| ParseError::end_of_line(line_number, line, axis))?;
+        let raw = raw
+            .strip_prefix(axis)
+            .ok_or_else(|| ParseError::at(line_number, line, raw, axis))?;
+        parse_range(line_number, line, raw)
+    });
+
+    let cuboid = Cuboid {
+        x: axes.next().unwrap()?,
+        y: axes.next().unwrap()?,
+        z: axes.next().unwrap()?,
+    };
+
+    match ranges.next() {
+        Some(extra) => Err(ParseError::at(line_number, line, extra, "end of line")),
+        None => Ok(Step { on, cuboid }),
+    }
+}
+
+fn parse_range(
+    line_number: usize,
+    line: &str,
+    raw: &str,
+) -> Result<RangeInclusive<i64>, ParseError> {
+    let (start, end) = raw
+        .split_once("..")
+        .ok_or_else(|| ParseError::at(line_number, line, raw, "start..end"))?;
+    let start = parse::number(line_number, line, start)?;
+    let end = parse::number(line_number, line, end)?;
+
+    if start > end {
+        return Err(ParseError::at(line_number, line, raw, "start <= end"));
+    }
+    Ok(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{checked::Overflow, parse::ParseError, Solution};
+
+    use crate::{parse_input, Day22};
+
+    #[test]
+    fn test_example() {
+        let steps = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day22::part1(&steps), Ok(39));
+        assert_eq!(Day22::part2(&steps), Ok(39));
+
+        let steps = parse_input(include_str!("test.input.large.txt")).unwrap();
+        assert_eq!(Day22::part1(&steps), Ok(474140));
+        assert_eq!(Day22::part2(&steps), Ok(2758514936282235));
+    }
+
+    #[test]
+    fn test_outside_initialization_region() {
+        let input = "on x=-60..-49,y=0..1,z=0..0\noff x=-55..-50,y=0..0,z=0..0\n";
+        let steps = parse_input(input).unwrap();
+        assert_eq!(Day22::part1(&steps), Ok(3));
+        assert_eq!(Day22::part2(&steps), Ok(18));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "on x=0..3037000498,y=0..3037000498,z=0..0";
+        assert_eq!(
+            Day22::part2(&parse_input(input).unwrap()),
+            Ok(3037000499 * 3037000499)
+        );
+
+        let input = "on x=0..3037000499,y=0..3037000499,z=0..0";
+        let too_big = Err(Overflow("cuboid volume"));
+        assert_eq!(Day22::part2(&parse_input(input).unwrap()), too_big);
+        let input = format!("on x={}..{},y=0..0,z=0..0", i64::MIN, i64::MAX);
+        assert_eq!(Day22::part2(&parse_input(&input).unwrap()), too_big);
+
+        let input = "on x=0..3037000498,y=0..3037000498,z=0..0\n\
+                     on x=-6000000000..-1,y=0..0,z=0..0";
+        assert_eq!(
+            Day22::part2(&parse_input(input).unwrap()),
+            Err(Overflow("cubes on"))
+        );
+
+        // More than fit in an i64 until the first cuboid is turned off again
+        let input = "on x=0..1999999,y=0..1999999,z=0..1999999
+                     on x=2000000..3999999,y=0..1999999,z=0..1999999
+                     off x=0..1999999,y=0..1999999,z=0..1999999";
+        assert_eq!(
+            Day22::part2(&parse_input(input).unwrap()),
+            Ok(8_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("toggle x=1..2,y=1..2,z=1..2"),
+            Err(ParseError::new(
+                1,
+                1,
+                "on or off",
+                "toggle x=1..2,y=1..2,z=1..2"
+            ))
+        );
+        assert_eq!(
+            parse_input("on x=1..2,y=1..2"),
+            Err(ParseError::new(1, 17, "z=", ""))
+        );
+        assert_eq!(
+            parse_input("on x=1..2,y=1..x,z=1..2"),
+            Err(ParseError::new(1, 16, "number", "x"))
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use common::{input, trace, Part, Solution};
+use day_22::Day22;
+
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day22::DAY, env!("CARGO_MANIFEST_DIR"));
+    let steps = common::parse_or_exit::<Day22>(&input);
+
+    let part1 = common::answer_or_exit::<Day22>(Part::One, trace::part1::<Day22>(&steps));
+    println!("Part 1: {}", part1);
+
+    let part2 = common::answer_or_exit::<Day22>(Part::Two, trace::part2::<Day22>(&steps));
+    println!("Part 2: {}", part2);
+}
@@ -0,0 +1,273 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use common::{parse::ParseError, NoAnswer, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Burrow;
+    type Part1 = Result<u32, NoAnswer>;
+    type Part2 = Result<u32, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(burrow: &Self::Input) -> Self::Part1 {
+        solve(burrow).ok_or(UNSOLVABLE)
+    }
+
+    fn part2(burrow: &Self::Input) -> Self::Part2 {
+        solve(&burrow.unfold()).ok_or(UNSOLVABLE)
+    }
+}
+
+const UNSOLVABLE: NoAnswer = NoAnswer("the amphipods cannot be organized");
+
+const HALLWAY: usize = 11;
+const ROOMS: usize = 4;
+const MAX_DEPTH: usize = 4;
+// Hallway positions right above each room, amphipods never stop there
+const ENTRANCES: [usize; ROOMS] = [2, 4, 6, 8];
+const ENERGY: [u32; ROOMS] = [1, 10, 100, 1000];
+// The two rows folded out of the diagram for part 2
+const UNFOLDED: [[u8; ROOMS]; 2] = [[4, 3, 2, 1], [4, 2, 1, 3]];
+
+/// Amphipods are stored as 1 (A) to 4 (D), with 0 marking free space. Room slot 0 is the
+/// one next to the hallway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Burrow {
+    hallway: [u8; HALLWAY],
+    rooms: [[u8; MAX_DEPTH]; ROOMS],
+    depth: usize,
+}
+
+impl Burrow {
+    /// Inserts the two rows hidden in the folded part of the diagram.
+    pub fn unfold(&self) -> Burrow {
+        assert_eq!(self.depth, 2, "burrow is already unfolded");
+
+        let mut unfolded = *self;
+        for (room, slots) in unfolded.rooms.iter_mut().enumerate() {
+            *slots = [
+                self.rooms[room][0],
+                UNFOLDED[0][room],
+                UNFOLDED[1][room],
+                self.rooms[room][1],
+            ];
+        }
+        unfolded.depth = MAX_DEPTH;
+        unfolded
+    }
+
+    fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| self.room_is_settled(room, 0))
+    }
+
+    /// Whether the slots from `from` down only hold amphipods that belong in `room`.
+    fn room_is_settled(&self, room: usize, from: usize) -> bool {
+        self.rooms[room][from..self.depth]
+            .iter()
+            .all(|&amphipod| amphipod as usize == room + 1)
+    }
+
+    fn hallway_is_clear(&self, from: usize, to: usize) -> bool {
+        let (start, end) = if from < to {
+            (from + 1, to)
+        } else {
+            (to, from - 1)
+        };
+        self.hallway[start..=end].iter().all(|&cell| cell == 0)
+    }
+
+    /// Every burrow reachable with a single move, with the energy it takes.
+    fn moves(&self) -> Vec<(Burrow, u32)> {
+        let mut moves = vec![];
+
+        // Into the destination room, which has to be free of strangers
+        for position in 0..HALLWAY {
+            let amphipod = self.hallway[position];
+            if amphipod == 0 {
+                continue;
+            }
+            let room = amphipod as usize - 1;
+            let entrance = ENTRANCES[room];
+            let occupied = self.rooms[room][..self.depth]
+                .iter()
+                .any(|&other| other != 0 && other != amphipod);
+            if occupied || !self.hallway_is_clear(position, entrance) {
+                continue;
+            }
+
+            let Some(slot) = (0..self.depth)
+                .rev()
+                .find(|&slot| self.rooms[room][slot] == 0)
+            else {
+                continue;
+            };
+            let steps = position.abs_diff(entrance) + slot + 1;
+
+            let mut next = *self;
+            next.hallway[position] = 0;
+            next.rooms[room][slot] = amphipod;
+            moves.push((next, steps as u32 * ENERGY[room]));
+        }
+
+        // Out of a room, unless everyone below already is where they belong
+        for (room, &entrance) in ENTRANCES.iter().enumerate() {
+            let Some(slot) = (0..self.depth).find(|&slot| self.rooms[room][slot] != 0) else {
+                continue;
+            };
+            if self.room_is_settled(room, slot) {
+                continue;
+            }
+
+            let amphipod = self.rooms[room][slot];
+            for position in (0..HALLWAY).filter(|position| !ENTRANCES.contains(position)) {
+                if !self.hallway_is_clear(entrance, position) || self.hallway[position] != 0 {
+                    continue;
+                }
+                let steps = slot + 1 + position.abs_diff(entrance);
+
+                let mut next = *self;
+                next.rooms[room][slot] = 0;
+                next.hallway[position] = amphipod;
+                moves.push((next, steps as u32 * ENERGY[amphipod as usize - 1]));
+            }
+        }
+
+        moves
+    }
+}
+
+/// Least energy needed to organize the burrow, using Dijkstra over burrow states.
+pub fn solve(burrow: &Burrow) -> Option<u32> {
+    let mut best = HashMap::from([(*burrow, 0)]);
+    let mut queue = BinaryHeap::from([Reverse((0, *burrow))]);
+
+    let mut pops = 0;
+
+    while let Some(Reverse((energy, current))) = queue.pop() {
+        pops += 1;
+        if current.is_organized() {
+            tracing::debug!(pops, states = best.len(), "organized");
+            return Some(energy);
+        }
+        if best.get(&current).is_some_and(|&known| known < energy) {
+            continue;
+        }
+
+        for (next, cost) in current.moves() {
+            let energy = energy + cost;
+            if best.get(&next).is_none_or(|&known| energy < known) {
+                best.insert(next, energy);
+                queue.push(Reverse((energy, next)));
+            }
+        }
+    }
+
+    tracing::debug!(pops, states = best.len(), "no way to organize");
+    None
+}
+
+fn parse_input(input: &str) -> Result<Burrow, ParseError> {
+    let mut burrow = Burrow {
+        hallway: [0; HALLWAY],
+        rooms: [[0; MAX_DEPTH]; ROOMS],
+        depth: 0,
+    };
+
+    // The first two lines are the wall and the empty hallway, then one line per room row
+    let lines = input.lines().collect::<Vec<_>>();
+    for (i, line) in lines.iter().enumerate().skip(2) {
+        let amphipods = line
+            .char_indices()
+            .filter(|(_, c)| !matches!(c, '#' | ' '))
+            .collect::<Vec<_>>();
+        if amphipods.is_empty() {
+            continue;
+        }
+        if burrow.depth == 2 {
+            return Err(ParseError::at(i + 1, line, line.trim(), "end of burrow"));
+        }
+
+        for (room, (offset, c)) in amphipods.iter().enumerate() {
+            let amphipod = match c {
+                'A'..='D' => *c as u8 - b'A' + 1,
+                _ => {
+                    return Err(ParseError::at(
+                        i + 1,
+                        line,
+                        &line[*offset..],
+                        "amphipod A-D",
+                    ))
+                }
+            };
+            if room >= ROOMS {
+                return Err(ParseError::at(i + 1, line, &line[*offset..], "'#'"));
+            }
+            burrow.rooms[room][burrow.depth] = amphipod;
+        }
+        if amphipods.len() < ROOMS {
+            return Err(ParseError::end_of_line(i + 1, line, "4 amphipods"));
+        }
+        burrow.depth += 1;
+    }
+
+    if burrow.depth < 2 {
+        return Err(ParseError::new(
+            lines.len() + 1,
+            1,
+            "2 rows of amphipods",
+            "",
+        ));
+    }
+
+    Ok(burrow)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse::ParseError, NoAnswer, Solution};
+
+    use crate::{parse_input, Day23};
+
+    #[test]
+    fn test_part1() {
+        let burrow = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day23::part1(&burrow), Ok(12521));
+    }
+
+    #[test]
+    fn test_part2() {
+        let burrow = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(Day23::part2(&burrow), Ok(44169));
+    }
+
+    #[test]
+    fn test_unsolvable() {
+        let burrow =
+            parse_input("#############\n#...........#\n###A#A#A#A###\n  #A#A#A#A#\n  #########")
+                .unwrap();
+        let unsolvable = Err(NoAnswer("the amphipods cannot be organized"));
+        assert_eq!(Day23::part1(&burrow), unsolvable);
+        assert_eq!(Day23::part2(&burrow), unsolvable);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("#############\n#...........#\n###B#C#B#E###\n").err();
+        assert_eq!(error, Some(ParseError::new(3, 10, "amphipod A-D", "E###")));
+
+        let error = parse_input("#############\n#...........#\n###B#C#B#D###\n").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(4, 1, "2 rows of amphipods", ""))
+        );
+    }
+}
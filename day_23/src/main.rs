@@ -0,0 +1,14 @@
+use common::{input, trace, Part, Solution};
+use day_23::Day23;
+
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day23::DAY, env!("CARGO_MANIFEST_DIR"));
+    let burrow = common::parse_or_exit::<Day23>(&input);
+
+    let part1 = common::answer_or_exit::<Day23>(Part::One, trace::part1::<Day23>(&burrow));
+    println!("Part 1: {}", part1);
+
+    let part2 = common::answer_or_exit::<Day23>(Part::Two, trace::part2::<Day23>(&burrow));
+    println!("Part 2: {}", part2);
+}
@@ -0,0 +1,344 @@
+use std::fmt::Display;
+
+use common::{
+    parse::{self, ParseError},
+    NoAnswer, Solution,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Instruction>;
+    type Part1 = Result<u64, NoAnswer>;
+    type Part2 = Result<u64, NoAnswer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Self::Input) -> Self::Part1 {
+        model_number(program, Search::Largest).ok_or(NOT_MONAD)
+    }
+
+    fn part2(program: &Self::Input) -> Self::Part2 {
+        model_number(program, Search::Smallest).ok_or(NOT_MONAD)
+    }
+}
+
+const NOT_MONAD: NoAnswer = NoAnswer("the program is not a MONAD");
+
+/// One of the registers `w`, `x`, `y` and `z`, as an index into [`Registers`].
+pub type Register = usize;
+pub type Registers = [i64; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AluError {
+    /// `inp` was executed with no input left
+    MissingInput(usize),
+    DivisionByZero(usize),
+    /// `mod` with a negative dividend or a non-positive divisor
+    InvalidModulo(usize),
+    /// A result that does not fit an `i64`
+    Overflow(usize),
+}
+
+impl Display for AluError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AluError::MissingInput(i) => write!(f, "instruction {}: no input left", i + 1),
+            AluError::DivisionByZero(i) => write!(f, "instruction {}: division by zero", i + 1),
+            AluError::InvalidModulo(i) => write!(f, "instruction {}: invalid modulo", i + 1),
+            AluError::Overflow(i) => write!(f, "instruction {}: out of range", i + 1),
+        }
+    }
+}
+
+impl std::error::Error for AluError {}
+
+/// Runs `program` from zeroed registers, feeding `inp` from `input`.
+pub fn run(program: &[Instruction], input: &[i64]) -> Result<Registers, AluError> {
+    let mut registers = [0; 4];
+    let mut input = input.iter();
+
+    for (i, instruction) in program.iter().enumerate() {
+        let value = |operand: Operand| match operand {
+            Operand::Register(register) => registers[register],
+            Operand::Number(n) => n,
+        };
+
+        match *instruction {
+            Instruction::Inp(a) => {
+                registers[a] = *input.next().ok_or(AluError::MissingInput(i))?;
+            }
+            Instruction::Add(a, b) => {
+                registers[a] = registers[a]
+                    .checked_add(value(b))
+                    .ok_or(AluError::Overflow(i))?;
+            }
+            Instruction::Mul(a, b) => {
+                registers[a] = registers[a]
+                    .checked_mul(value(b))
+                    .ok_or(AluError::Overflow(i))?;
+            }
+            Instruction::Div(a, b) => {
+                let b = value(b);
+                if b == 0 {
+                    return Err(AluError::DivisionByZero(i));
+                }
+                // Only i64::MIN / -1 is out of range
+                registers[a] = registers[a].checked_div(b).ok_or(AluError::Overflow(i))?;
+            }
+            Instruction::Mod(a, b) => {
+                let b = value(b);
+                if registers[a] < 0 || b <= 0 {
+                    return Err(AluError::InvalidModulo(i));
+                }
+                registers[a] %= b;
+            }
+            Instruction::Eql(a, b) => registers[a] = (registers[a] == value(b)) as i64,
+        }
+    }
+
+    Ok(registers)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Search {
+    Largest,
+    Smallest,
+}
+
+const DIGITS: usize = 14;
+const BLOCK_LENGTH: usize = 18;
+
+/// The three constants that differ between the 14 digit checks of a MONAD program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Block {
+    /// 1 pushes a base-26 digit onto `z`, 26 pops one
+    div_z: i64,
+    add_x: i64,
+    add_y: i64,
+}
+
+/// Finds the largest or smallest 14-digit model number without zeros that MONAD accepts.
+///
+/// MONAD treats `z` as a base-26 stack: every pushing block stores `digit + add_y`, and
+/// every popping block only leaves `z` alone when its digit equals the popped value plus its
+/// `add_x`. Pairing pushes and pops therefore fixes the difference between two digits, which
+/// leaves a single best choice per pair. The result is double-checked on the ALU.
+pub fn model_number(program: &[Instruction], search: Search) -> Option<u64> {
+    let blocks = blocks(program)?;
+    let mut digits = [0i64; DIGITS];
+    let mut stack = vec![];
+
+    for (i, block) in blocks.iter().enumerate() {
+        if block.div_z == 1 {
+            stack.push((i, block.add_y));
+            continue;
+        }
+
+        let (j, add_y) = stack.pop()?;
+        // digits[i] = digits[j] + difference
+        let difference = add_y.checked_add(block.add_x)?;
+        digits[j] = match search {
+            Search::Largest => 9.min(9i64.checked_sub(difference)?),
+            Search::Smallest => 1.max(1i64.checked_sub(difference)?),
+        };
+        digits[i] = digits[j].checked_add(difference)?;
+    }
+
+    tracing::debug!(blocks = blocks.len(), digits = ?digits, "digits paired up");
+    let valid = stack.is_empty() && digits.iter().all(|digit| (1..=9).contains(digit));
+    if !valid || run(program, &digits).ok()?[3] != 0 {
+        return None;
+    }
+
+    Some(
+        digits
+            .iter()
+            .fold(0, |number, digit| number * 10 + *digit as u64),
+    )
+}
+
+fn blocks(program: &[Instruction]) -> Option<Vec<Block>> {
+    if program.len() != DIGITS * BLOCK_LENGTH {
+        return None;
+    }
+
+    program
+        .chunks(BLOCK_LENGTH)
+        .map(|block| match (block[0], block[4], block[5], block[15]) {
+            (
+                Instruction::Inp(0),
+                Instruction::Div(3, Operand::Number(div_z @ (1 | 26))),
+                Instruction::Add(1, Operand::Number(add_x)),
+                Instruction::Add(2, Operand::Number(add_y)),
+            ) => Some(Block {
+                div_z,
+                add_x,
+                add_y,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_instruction(i + 1, line))
+        .collect()
+}
+
+fn parse_instruction(line_number: usize, line: &str) -> Result<Instruction, ParseError> {
+    let mut tokens = line.split_whitespace();
+    let operation = tokens.next().unwrap_or(line);
+    let a = parse_register(line_number, line, tokens.next())?;
+
+    let instruction = if operation == "inp" {
+        Instruction::Inp(a)
+    } else {
+        let b = match tokens.next() {
+            Some(token) if token.starts_with(char::is_alphabetic) => {
+                Operand::Register(parse_register(line_number, line, Some(token))?)
+            }
+            Some(token) => Operand::Number(parse::number(line_number, line, token)?),
+            None => return Err(ParseError::end_of_line(line_number, line, "operand")),
+        };
+
+        match operation {
+            "add" => Instruction::Add(a, b),
+            "mul" => Instruction::Mul(a, b),
+            "div" => Instruction::Div(a, b),
+            "mod" => Instruction::Mod(a, b),
+            "eql" => Instruction::Eql(a, b),
+            _ => return Err(ParseError::at(line_number, line, operation, "instruction")),
+        }
+    };
+
+    match tokens.next() {
+        Some(extra) => Err(ParseError::at(line_number, line, extra, "end of line")),
+        None => Ok(instruction),
+    }
+}
+
+fn parse_register(
+    line_number: usize,
+    line: &str,
+    token: Option<&str>,
+) -> Result<Register, ParseError> {
+    match token {
+        Some("w") => Ok(0),
+        Some("x") => Ok(1),
+        Some("y") => Ok(2),
+        Some("z") => Ok(3),
+        Some(other) => Err(ParseError::at(line_number, line, other, "register w-z")),
+        None => Err(ParseError::end_of_line(line_number, line, "register w-z")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse::ParseError, NoAnswer, Solution};
+
+    use crate::{model_number, parse_input, run, AluError, Day24, Instruction, Operand, Search};
+
+    #[test]
+    fn test_binary() {
+        let program = parse_input(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\n\
+             add x w\nmod x 2\ndiv w 2\nmod w 2",
+        )
+        .unwrap();
+        assert_eq!(run(&program, &[11]), Ok([1, 0, 1, 1]));
+        assert_eq!(run(&program, &[6]), Ok([0, 1, 1, 0]));
+        assert_eq!(run(&program, &[]), Err(AluError::MissingInput(0)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let program = parse_input("inp w\nmul w 9999999999\nmul w 9999999999").unwrap();
+        assert_eq!(run(&program, &[1]), Err(AluError::Overflow(2)));
+
+        let program = parse_input("inp w\nadd w -1\nadd w -9223372036854775807").unwrap();
+        assert_eq!(run(&program, &[0]), Ok([i64::MIN, 0, 0, 0]));
+        let program = parse_input("inp w\nadd w -1\nadd w -9223372036854775808").unwrap();
+        assert_eq!(run(&program, &[0]), Err(AluError::Overflow(2)));
+    }
+
+    #[test]
+    fn test_three_times() {
+        let program = parse_input("inp z\ninp x\nmul z 3\neql z x").unwrap();
+        assert_eq!(run(&program, &[2, 6]).unwrap()[3], 1);
+        assert_eq!(run(&program, &[2, 7]).unwrap()[3], 0);
+    }
+
+    #[test]
+    fn test_model_number() {
+        // Generated MONAD with digit pairs (0, 1), (2, 3) ... differing by 3, -2, 0, 8, -8, 1, -5
+        let program = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(
+            model_number(&program, Search::Largest),
+            Some(69979919918994)
+        );
+        assert_eq!(
+            model_number(&program, Search::Smallest),
+            Some(14311119911261)
+        );
+        assert_eq!(model_number(&program[18..], Search::Largest), None);
+    }
+
+    #[test]
+    fn test_not_monad() {
+        let not_monad = Err(NoAnswer("the program is not a MONAD"));
+        let program = Day24::parse("inp w\nmul w 9999999999\nmul w 9999999999").unwrap();
+        assert_eq!(Day24::part1(&program), not_monad);
+        assert_eq!(Day24::part2(&Day24::parse("").unwrap()), not_monad);
+
+        // Blocks 0 and 1 pair up, with a difference out of range
+        let monad = Day24::parse(include_str!("test.input.txt")).unwrap();
+        for (add_y, add_x) in [(i64::MAX, 1), (i64::MIN, 0), (i64::MIN + 8, 0)] {
+            let mut program = monad.clone();
+            program[15] = Instruction::Add(2, Operand::Number(add_y));
+            program[18 + 5] = Instruction::Add(1, Operand::Number(add_x));
+            assert_eq!(Day24::part1(&program), not_monad);
+            assert_eq!(Day24::part2(&program), not_monad);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("inp w\nadd q 1"),
+            Err(ParseError::new(2, 5, "register w-z", "q"))
+        );
+        assert_eq!(
+            parse_input("sub x 1"),
+            Err(ParseError::new(1, 1, "instruction", "sub"))
+        );
+        assert_eq!(
+            parse_input("mul x"),
+            Err(ParseError::new(1, 6, "operand", ""))
+        );
+    }
+}
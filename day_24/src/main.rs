@@ -0,0 +1,14 @@
+use common::{input, trace, Part, Solution};
+use day_24::Day24;
+
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day24::DAY, env!("CARGO_MANIFEST_DIR"));
+    let program = common::parse_or_exit::<Day24>(&input);
+
+    let part1 = common::answer_or_exit::<Day24>(Part::One, trace::part1::<Day24>(&program));
+    println!("Part 1: {}", part1);
+
+    let part2 = common::answer_or_exit::<Day24>(Part::Two, trace::part2::<Day24>(&program));
+    println!("Part 2: {}", part2);
+}
@@ -0,0 +1,123 @@
+use common::{parse::ParseError, NoAnswer, Solution};
+use grid::Grid;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Grid<Cell>;
+    type Part1 = Result<usize, NoAnswer>;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(seafloor: &Self::Input) -> Self::Part1 {
+        solve(seafloor.clone())
+    }
+
+    // The last day only has a single puzzle
+    fn part2(_: &Self::Input) -> Self::Part2 {
+        "-".to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+fn parse_input(input: &str) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse_with(input, "'>', 'v' or '.'", |c| match c {
+        '.' => Some(Cell::Empty),
+        '>' => Some(Cell::East),
+        'v' => Some(Cell::South),
+        _ => None,
+    })
+}
+
+/// First step on which no sea cucumber moves, or an error if they keep going round in circles.
+fn solve(mut seafloor: Grid<Cell>) -> Result<usize, NoAnswer> {
+    // Compared against a snapshot taken at every power of two, a repeating state shows up
+    // within twice the length of the cycle after it is entered
+    let mut snapshot = seafloor.clone();
+    let mut steps = 1usize;
+    while step(&mut seafloor) {
+        if seafloor == snapshot {
+            tracing::debug!(steps, "state repeats");
+            return Err(NoAnswer("the sea cucumbers never stop moving"));
+        }
+        if steps.is_power_of_two() {
+            snapshot = seafloor.clone();
+        }
+        steps += 1;
+    }
+    tracing::debug!(steps);
+    Ok(steps)
+}
+
+/// Moves the east-facing herd and then the south-facing one, both wrapping around the edges.
+/// Returns whether any sea cucumber moved.
+fn step(seafloor: &mut Grid<Cell>) -> bool {
+    let east = move_herd(seafloor, Cell::East, |(x, y), width, _| {
+        ((x + 1) % width, y)
+    });
+    let south = move_herd(seafloor, Cell::South, |(x, y), _, height| {
+        (x, (y + 1) % height)
+    });
+    east || south
+}
+
+fn move_herd<F>(seafloor: &mut Grid<Cell>, herd: Cell, target: F) -> bool
+where
+    F: Fn((usize, usize), usize, usize) -> (usize, usize),
+{
+    let (width, height) = (seafloor.width(), seafloor.height());
+    // Everyone in the herd looks before anyone moves
+    let moves = seafloor
+        .iter()
+        .filter(|(_, cell)| **cell == herd)
+        .map(|(position, _)| (position, target(position, width, height)))
+        .filter(|(_, target)| seafloor[*target] == Cell::Empty)
+        .collect::<Vec<_>>();
+
+    for (from, to) in &moves {
+        seafloor[*from] = Cell::Empty;
+        seafloor[*to] = herd;
+    }
+
+    !moves.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::NoAnswer;
+
+    use crate::{parse_input, solve, step};
+
+    #[test]
+    fn test_step() {
+        let mut seafloor = parse_input("...>>>>>...").unwrap();
+        step(&mut seafloor);
+        assert_eq!(seafloor, parse_input("...>>>>.>..").unwrap());
+        step(&mut seafloor);
+        assert_eq!(seafloor, parse_input("...>>>.>.>.").unwrap());
+    }
+
+    #[test]
+    fn test_part1() {
+        let seafloor = parse_input(include_str!("test.input.txt")).unwrap();
+        assert_eq!(solve(seafloor), Ok(58));
+    }
+
+    #[test]
+    fn test_never_stops() {
+        let never_stops = Err(NoAnswer("the sea cucumbers never stop moving"));
+        assert_eq!(solve(parse_input("...\n>v.").unwrap()), never_stops);
+        assert_eq!(solve(parse_input(">.").unwrap()), never_stops);
+    }
+}
@@ -0,0 +1,14 @@
+use common::{input, trace, Part, Solution};
+use day_25::Day25;
+
+fn main() {
+    trace::init();
+    let input = input::load_or_exit(Day25::DAY, env!("CARGO_MANIFEST_DIR"));
+    let seafloor = common::parse_or_exit::<Day25>(&input);
+
+    let part1 = common::answer_or_exit::<Day25>(Part::One, trace::part1::<Day25>(&seafloor));
+    println!("Part 1: {}", part1);
+
+    let part2 = trace::part2::<Day25>(&seafloor);
+    println!("Part 2: {}", part2);
+}
@@ -0,0 +1,23 @@
+#![no_main]
+
+//! Every day's parser and, on input that parses, both parts, picked by the first byte of the
+//! input (1 to 25).
+
+use common::Part;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, rest)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(rest) else {
+        return;
+    };
+
+    let Some(day) = common::days!().into_iter().find(|d| d.number == day) else {
+        return;
+    };
+    // Errors are fine, panics are what the fuzzer is after
+    let _ = day.run(input, Part::One);
+    let _ = day.run(input, Part::Two);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use common::Solution;
+use day_20::Day20;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = Day20::parse(input);
+    }
+});
@@ -0,0 +1,15 @@
+use std::io::{self, Write};
+
+use rand::Rng;
+
+use crate::SeededRng;
+
+/// `size` sonar depths, drifting up and down from a starting depth of around 100.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut depth = rng.gen_range(100..200u64);
+    for _ in 0..size {
+        writeln!(out, "{}", depth)?;
+        depth = depth.saturating_add_signed(rng.gen_range(-10..=20));
+    }
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+
+use rand::Rng;
+
+use crate::SeededRng;
+
+/// `size` submarine commands. As in the puzzle, `up` never takes the submarine above the surface.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut depth = 0;
+    for _ in 0..size {
+        let units = rng.gen_range(1..10);
+        match rng.gen_range(0..3) {
+            0 => writeln!(out, "forward {}", units)?,
+            1 if depth >= units => {
+                depth -= units;
+                writeln!(out, "up {}", units)?;
+            }
+            _ => {
+                depth += units;
+                writeln!(out, "down {}", units)?;
+            }
+        }
+    }
+    Ok(())
+}
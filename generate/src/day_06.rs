@@ -0,0 +1,21 @@
+use std::io::{self, Write};
+
+use rand::Rng;
+
+use crate::SeededRng;
+
+/// `size` lanternfish, with the timers of 1 to 5 days found in the puzzle inputs.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    write_list(out, (0..size).map(|_| rng.gen_range(1..=5)))
+}
+
+/// Writes a single comma separated line.
+pub fn write_list(out: &mut dyn Write, values: impl Iterator<Item = u32>) -> io::Result<()> {
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            write!(out, ",")?;
+        }
+        write!(out, "{}", value)?;
+    }
+    writeln!(out)
+}
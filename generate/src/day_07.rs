@@ -0,0 +1,10 @@
+use std::io::{self, Write};
+
+use rand::Rng;
+
+use crate::{day_06::write_list, SeededRng};
+
+/// `size` crab positions, spread over the same range of 0 to 2000 as the puzzle inputs.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    write_list(out, (0..size).map(|_| rng.gen_range(0..=2000)))
+}
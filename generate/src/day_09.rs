@@ -0,0 +1,8 @@
+use std::io::{self, Write};
+
+use crate::SeededRng;
+
+/// A `size`×`size` heightmap.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    crate::grid::digits(rng, size, 0..=9, out)
+}
@@ -0,0 +1,8 @@
+use std::io::{self, Write};
+
+use crate::SeededRng;
+
+/// A `size`×`size` map of risk levels, which never are 0.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    crate::grid::digits(rng, size, 1..=9, out)
+}
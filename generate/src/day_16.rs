@@ -0,0 +1,73 @@
+use std::io::{self, Write};
+
+use day_16::{Packet, MAX_DEPTH};
+use rand::Rng;
+
+use crate::SeededRng;
+
+/// A transmission holding `size` packets in total.
+///
+/// Products only ever multiply literals, so that evaluating the transmission does not
+/// overflow.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    write(out, &packet(rng, size.max(1), MAX_DEPTH))
+}
+
+/// Like [`generate`], with the packets wrapped in `depth` sums, minimums and maximums of a
+/// single sub-packet each. `depth` may be at most [`MAX_DEPTH`].
+pub fn generate_nested(
+    rng: &mut SeededRng,
+    size: usize,
+    depth: usize,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let mut nested = packet(rng, size.saturating_sub(depth).max(1), MAX_DEPTH - depth);
+    for _ in 0..depth {
+        let type_id = [0, 2, 3][rng.gen_range(0..3)];
+        nested = operation(rng.gen_range(0..8), type_id, vec![nested]);
+    }
+    write(out, &nested)
+}
+
+fn write(out: &mut dyn Write, packet: &Packet) -> io::Result<()> {
+    let transmission = day_16::encode(packet).map_err(io::Error::other)?;
+    writeln!(out, "{}", transmission)
+}
+
+/// A packet of `size` packets with at most `depth` levels of operators. Should the tree get
+/// that deep, the packets left over are dropped.
+fn packet<R: Rng>(rng: &mut R, size: usize, depth: usize) -> Packet {
+    let version = rng.gen_range(0..8);
+    if size == 1 || depth == 0 {
+        return Packet::literal(version, rng.gen_range(0..16));
+    }
+
+    if size == 3 && rng.gen_bool(0.5) {
+        // Comparison of two literals
+        let subpackets = (0..2).map(|_| packet(rng, 1, 0)).collect();
+        return operation(version, rng.gen_range(5..=7), subpackets);
+    }
+    if size <= 5 && rng.gen_bool(0.5) {
+        let subpackets = (1..size).map(|_| packet(rng, 1, 0)).collect();
+        return operation(version, 1, subpackets);
+    }
+
+    // Sum, minimum or maximum, splitting the remaining packets between the sub-packets
+    let mut remaining = size - 1;
+    let mut subpackets = vec![];
+    while remaining > 0 {
+        let size = match rng.gen_range(0..4) {
+            0 => remaining,
+            _ => rng.gen_range(1..=remaining),
+        };
+        subpackets.push(packet(rng, size, depth - 1));
+        remaining -= size;
+    }
+    let type_id = [0, 2, 3][rng.gen_range(0..3)];
+    operation(version, type_id, subpackets)
+}
+
+/// Operators are only ever generated with a fitting number of sub-packets.
+fn operation(version: u32, type_id: u32, subpackets: Vec<Packet>) -> Packet {
+    Packet::operation(version, type_id, subpackets).expect("generated operators are valid")
+}
@@ -0,0 +1,31 @@
+use std::io::{self, Write};
+
+use rand::Rng;
+
+use crate::SeededRng;
+
+/// `size` reduced snailfish numbers, with pairs nested at most four deep.
+pub fn generate(rng: &mut SeededRng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let mut number = String::new();
+        pair(rng, 0, &mut number);
+        writeln!(out, "{}", number)?;
+    }
+    Ok(())
+}
+
+fn pair<R: Rng>(rng: &mut R, depth: usize, number: &mut String) {
+    number.push('[');
+    element(rng, depth + 1, number);
+    number.push(',');
+    element(rng, depth + 1, number);
+    number.push(']');
+}
+
+fn element<R: Rng>(rng: &mut R, depth: usize, number: &mut String) {
+    if depth < 4 && rng.gen_bool(0.6) {
+        pair(rng, depth, number);
+    } else {
+        number.push(char::from(b'0' + rng.gen_range(0..10)));
+    }
+}
@@ -0,0 +1,26 @@
+use std::{
+    io::{self, Write},
+    ops::RangeInclusive,
+};
+
+use rand::Rng;
+
+use crate::SeededRng;
+
+/// A `size`×`size` grid of random digits from `digits`.
+pub fn digits(
+    rng: &mut SeededRng,
+    size: usize,
+    digits: RangeInclusive<u8>,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let mut row = vec![0; size + 1];
+    for _ in 0..size {
+        for cell in &mut row[..size] {
+            *cell = b'0' + rng.gen_range(digits.clone());
+        }
+        row[size] = b'\n';
+        out.write_all(&row)?;
+    }
+    Ok(())
+}
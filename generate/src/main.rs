@@ -0,0 +1,225 @@
+use std::{
+    env, fs,
+    io::{self, BufWriter, Write},
+    process,
+};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day_01;
+mod day_02;
+mod day_06;
+mod day_07;
+mod day_09;
+mod day_15;
+mod day_16;
+mod day_18;
+mod grid;
+
+const USAGE: &str = "Usage: generate <day> <size> [--seed <n>] [--output <path>] [--depth <n>]
+
+Writes a random but valid puzzle input for <day> to stdout or --output. The same seed
+always produces the same input. What <size> counts depends on the day:
+
+  01  sonar depths            02  commands
+  06  lanternfish             07  crabs
+  09  heightmap side length   15  risk map side length
+  16  packets                 18  snailfish numbers
+
+--depth nests the day 16 packets inside that many operators, at most 250.";
+
+/// ChaCha keeps producing the same numbers for a seed across `rand` releases
+pub type SeededRng = ChaCha8Rng;
+type Generator = fn(&mut SeededRng, usize, &mut dyn Write) -> io::Result<()>;
+
+fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day_01::generate,
+        2 => day_02::generate,
+        6 => day_06::generate,
+        7 => day_07::generate,
+        9 => day_09::generate,
+        15 => day_15::generate,
+        16 => day_16::generate,
+        18 => day_18::generate,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    day: u8,
+    size: usize,
+    seed: u64,
+    output: Option<String>,
+    depth: Option<usize>,
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let options = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("{}\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    let out: Box<dyn Write> = match &options.output {
+        Some(path) => match fs::File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(error) => {
+                eprintln!("Could not create {}: {}", path, error);
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+
+    if let Err(error) = generate(&options, &mut BufWriter::new(out)) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn generate(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let generator = generator(options.day).expect("day was checked while parsing arguments");
+    let mut rng = SeededRng::seed_from_u64(options.seed);
+    match options.depth {
+        Some(depth) => day_16::generate_nested(&mut rng, options.size, depth, out)?,
+        None => generator(&mut rng, options.size, out)?,
+    }
+    out.flush()
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter().map(String::as_str);
+
+    let day = match args.next() {
+        Some(raw) => raw
+            .parse()
+            .ok()
+            .filter(|day| generator(*day).is_some())
+            .ok_or_else(|| format!("No generator for day '{}'", raw))?,
+        None => return Err("Missing day".to_string()),
+    };
+    let size = match args.next() {
+        Some(raw) => raw.parse().map_err(|_| format!("Invalid size '{}'", raw))?,
+        None => return Err("Missing size".to_string()),
+    };
+
+    let mut options = Options {
+        day,
+        size,
+        seed: 0,
+        output: None,
+        depth: None,
+    };
+    while let Some(arg) = args.next() {
+        match (arg, args.next()) {
+            ("--seed" | "-s", Some(raw)) => {
+                options.seed = raw.parse().map_err(|_| format!("Invalid seed '{}'", raw))?
+            }
+            ("--output" | "-o", Some(path)) => options.output = Some(path.to_string()),
+            ("--depth" | "-d", Some(raw)) => {
+                let depth = raw
+                    .parse()
+                    .ok()
+                    .filter(|depth| *depth <= ::day_16::MAX_DEPTH)
+                    .ok_or_else(|| format!("Invalid depth '{}'", raw))?;
+                options.depth = Some(depth)
+            }
+            ("--seed" | "-s" | "--output" | "-o" | "--depth" | "-d", None) => {
+                return Err(format!("Missing value after {}", arg))
+            }
+            (other, _) => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+
+    if options.depth.is_some() && options.day != 16 {
+        return Err("Only day 16 takes a depth".to_string());
+    }
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::{generate, parse_args, Options};
+
+    fn input(day: u8, size: usize, seed: u64) -> String {
+        nested(day, size, seed, None)
+    }
+
+    fn nested(day: u8, size: usize, seed: u64, depth: Option<usize>) -> String {
+        let options = Options {
+            day,
+            size,
+            seed,
+            output: None,
+            depth,
+        };
+        let mut out = vec![];
+        generate(&options, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_inputs_parse() {
+        for seed in 0..5 {
+            assert!(day_01::Day01::parse(&input(1, 100, seed)).is_ok());
+            assert!(day_02::Day02::parse(&input(2, 100, seed)).is_ok());
+            assert!(day_06::Day06::parse(&input(6, 100, seed)).is_ok());
+            assert!(day_07::Day07::parse(&input(7, 100, seed)).is_ok());
+            assert!(day_09::Day09::parse(&input(9, 20, seed)).is_ok());
+            assert!(day_15::Day15::parse(&input(15, 20, seed)).is_ok());
+            assert!(day_18::Day18::parse(&input(18, 20, seed)).is_ok());
+
+            let transmission = day_16::Day16::parse(&input(16, 200, seed)).unwrap();
+            // Evaluating must not overflow
+            assert!(day_16::Day16::part2(&transmission).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_nested() {
+        for depth in [0, 1, 100, day_16::MAX_DEPTH] {
+            let transmission = day_16::Day16::parse(&nested(16, 300, 1, Some(depth))).unwrap();
+            assert!(day_16::Day16::part2(&transmission).is_ok());
+        }
+
+        // Three operators of 18 bits each around an 11-bit literal, padded to 17 hex digits
+        assert_eq!(nested(16, 1, 0, Some(3)).trim().len(), 17);
+    }
+
+    #[test]
+    fn test_seeded() {
+        assert_eq!(input(18, 10, 7), input(18, 10, 7));
+        assert_ne!(input(18, 10, 7), input(18, 10, 8));
+        assert_eq!(input(1, 1000, 0).lines().count(), 1000);
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = |raw: &str| raw.split_whitespace().map(String::from).collect::<Vec<_>>();
+        assert_eq!(
+            parse_args(&args("15 10000 --seed 3")),
+            Ok(Options {
+                day: 15,
+                size: 10000,
+                seed: 3,
+                output: None,
+                depth: None
+            })
+        );
+        assert_eq!(
+            parse_args(&args("16 100 --depth 250")).unwrap().depth,
+            Some(250)
+        );
+        assert!(parse_args(&args("16 100 --depth 251")).is_err());
+        assert!(parse_args(&args("18 100 --depth 3")).is_err());
+        assert!(parse_args(&args("3 100")).is_err());
+        assert!(parse_args(&args("1")).is_err());
+        assert!(parse_args(&args("1 100 --seed")).is_err());
+    }
+}
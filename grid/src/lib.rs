@@ -0,0 +1,311 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::parse::ParseError;
+
+/// A position on a [`Grid`], as `(x, y)` where `x` is the column and `y` the row.
+pub type Point = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Dense, row-major two-dimensional grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "cells do not fit {}x{}",
+            width,
+            height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, one row per line. All rows must have the same length,
+    /// `expected` describes a valid cell in errors.
+    pub fn parse_with<F>(input: &str, expected: &str, mut parse: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (row, line) in input.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+
+            let before = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                let cell =
+                    parse(c).ok_or_else(|| ParseError::new(row + 1, col + 1, expected, c))?;
+                cells.push(cell);
+            }
+
+            let line_width = cells.len() - before;
+            match width {
+                Some(width) if line_width < width => {
+                    return Err(ParseError::end_of_line(row + 1, line, expected));
+                }
+                Some(width) if line_width > width => {
+                    let column = line.char_indices().nth(width).map_or(0, |(i, _)| i);
+                    return Err(ParseError::at(row + 1, line, &line[column..], "end of row"));
+                }
+                _ => width = Some(line_width),
+            }
+            height += 1;
+        }
+
+        Ok(Self::from_vec(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_of(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Bounds-checked access with signed coordinates, for lookups that may step off the grid.
+    pub fn get_signed(&self, (x, y): (isize, isize)) -> Option<&T> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.get((x as usize, y as usize))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Horizontal and vertical neighbours that lie inside the grid.
+    pub fn neighbours4(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(position, &NEIGHBOURS_4)
+    }
+
+    /// Horizontal, vertical and diagonal neighbours that lie inside the grid.
+    pub fn neighbours8(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(position, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (x, y): Point,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(position).then_some(position)
+        })
+    }
+
+    fn index_of(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a block of single decimal digits, e.g. height or risk maps.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, "digit", |c| c.to_digit(10))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, "character", Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &Self::Output {
+        assert!(
+            self.contains(position),
+            "{:?} is outside of {}x{}",
+            position,
+            self.width,
+            self.height
+        );
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut Self::Output {
+        assert!(
+            self.contains(position),
+            "{:?} is outside of {}x{}",
+            position,
+            self.width,
+            self.height
+        );
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::Grid;
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_signed((-1, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Grid::parse_digits("12\n3x"),
+            Err(ParseError::new(2, 2, "digit", "x"))
+        );
+        assert_eq!(
+            Grid::parse_digits("12\n345"),
+            Err(ParseError::new(2, 3, "end of row", "5"))
+        );
+        assert_eq!(
+            Grid::parse_digits("12\n3"),
+            Err(ParseError::new(2, 2, "digit", ""))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        let corner = grid.neighbours4((0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_iter_positions() {
+        let grid = Grid::parse_chars("ab\ncd").unwrap();
+        let cells = grid.iter().map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            vec![((0, 0), 'a'), ((1, 0), 'b'), ((0, 1), 'c'), ((1, 1), 'd')]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Every day's solver behind a small C ABI, so that `index.html` can run them from a plain
+//! `wasm32-unknown-unknown` build without any generated glue code:
+//!
+//! ```text
+//! cargo build --release --target wasm32-unknown-unknown -p playground
+//! cp target/wasm32-unknown-unknown/release/playground.wasm playground/
+//! python3 -m http.server --directory playground
+//! ```
+//!
+//! The page copies the input into memory from [`alloc`], calls [`solve_part`] and reads the
+//! answer or error message from [`output`] and [`output_len`].
+
+use std::{cell::RefCell, slice, str};
+
+use common::{Answer, Day, Part, SolveError};
+
+fn days() -> Vec<Day> {
+    common::days!()
+}
+
+/// Solves one part of `day`, with a message to show instead on malformed input.
+pub fn solve(day: u8, part: Part, input: &str) -> Result<Answer, String> {
+    let solution = days()
+        .into_iter()
+        .find(|solution| solution.number == day)
+        .ok_or_else(|| format!("There is no day {}", day))?;
+
+    solution.run(input, part).map_err(|error| match error {
+        SolveError::Parse(error) => format!("Malformed input for day {}: {}", day, error),
+        error => format!("Day {} part {}: {}", day, part, error),
+    })
+}
+
+thread_local! {
+    /// Answer or error message of the last [`solve_part`] call
+    static OUTPUT: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Reserves `len` bytes for the page to copy an input into.
+#[no_mangle]
+pub extern "C" fn alloc(len: usize) -> *mut u8 {
+    let mut buffer = Vec::<u8>::with_capacity(len);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr
+}
+
+/// Frees memory reserved by [`alloc`].
+///
+/// # Safety
+///
+/// `ptr` has to come from `alloc(len)` and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn dealloc(ptr: *mut u8, len: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, len));
+}
+
+/// Solves part 1 or 2 of `day` for the UTF-8 input at `ptr`. Returns 0 when it was solved and
+/// 1 otherwise, either way leaving the text to show in [`output`].
+///
+/// # Safety
+///
+/// `ptr` has to point to `len` readable bytes, e.g. from [`alloc`].
+#[no_mangle]
+pub unsafe extern "C" fn solve_part(day: u32, part: u32, ptr: *const u8, len: usize) -> u32 {
+    let input = str::from_utf8(slice::from_raw_parts(ptr, len));
+    let part = match part {
+        1 => Some(Part::One),
+        2 => Some(Part::Two),
+        _ => None,
+    };
+
+    let result = match (u8::try_from(day), input, part) {
+        (Ok(day), Ok(input), Some(part)) => solve(day, part, input),
+        (Err(_), _, _) => Err(format!("There is no day {}", day)),
+        (_, Err(_), _) => Err("The input is not valid UTF-8".to_string()),
+        (_, _, None) => Err("There are only parts 1 and 2".to_string()),
+    };
+
+    let (status, text) = match result {
+        Ok(answer) => (0, answer.to_string()),
+        Err(message) => (1, message),
+    };
+    OUTPUT.with(|output| *output.borrow_mut() = text);
+    status
+}
+
+/// Start of the text left by [`solve_part`].
+#[no_mangle]
+pub extern "C" fn output() -> *const u8 {
+    OUTPUT.with(|output| output.borrow().as_ptr())
+}
+
+/// Length in bytes of the text left by [`solve_part`].
+#[no_mangle]
+pub extern "C" fn output_len() -> usize {
+    OUTPUT.with(|output| output.borrow().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::slice;
+
+    use common::{Answer, Part};
+
+    use crate::{alloc, dealloc, output, output_len, solve, solve_part};
+
+    #[test]
+    fn test_solve() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        assert_eq!(solve(1, Part::One, input), Ok(Answer::Number(7)));
+        assert_eq!(solve(1, Part::Two, input), Ok(Answer::Number(5)));
+        assert_eq!(
+            solve(26, Part::One, input),
+            Err("There is no day 26".to_string())
+        );
+        assert_eq!(
+            solve(1, Part::One, "199\nx"),
+            Err(
+                "Malformed input for day 1: line 2, column 1: expected number, found 'x'"
+                    .to_string()
+            )
+        );
+    }
+
+    /// Goes through the exports the same way the page does
+    fn call(day: u32, part: u32, input: &str) -> (u32, String) {
+        unsafe {
+            let ptr = alloc(input.len());
+            ptr.copy_from_nonoverlapping(input.as_ptr(), input.len());
+            let status = solve_part(day, part, ptr, input.len());
+            dealloc(ptr, input.len());
+
+            let text = slice::from_raw_parts(output(), output_len());
+            (status, String::from_utf8(text.to_vec()).unwrap())
+        }
+    }
+
+    #[test]
+    fn test_exports() {
+        assert_eq!(
+            call(2, 1, "forward 5\ndown 5\nforward 8"),
+            (0, "65".to_string())
+        );
+        assert_eq!(
+            call(2, 3, "forward 5"),
+            (1, "There are only parts 1 and 2".to_string())
+        );
+        assert_eq!(call(300, 1, ""), (1, "There is no day 300".to_string()));
+    }
+}
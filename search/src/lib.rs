@@ -0,0 +1,473 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A graph given by the edges leaving each node.
+///
+/// [`bfs`] and [`dfs`] only follow the edges and count hops, [`dijkstra`] and [`astar`] also
+/// add up their costs.
+pub trait Neighbours {
+    type Node: Clone + Eq + Hash;
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, Self::Cost)>;
+}
+
+/// Everything a search found out: the cost of every reached node, the node it was reached
+/// from, and the order in which nodes were reached.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    order: Vec<N>,
+    target: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new(start: N, cost: C) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), cost)]),
+            predecessors: HashMap::new(),
+            order: vec![start],
+            target: None,
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// Reached nodes, starting with the start node.
+    pub fn visited(&self) -> &[N] {
+        &self.order
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// The goal node a [`dijkstra`] or [`astar`] search stopped at.
+    pub fn target(&self) -> Option<&N> {
+        self.target.as_ref()
+    }
+
+    /// The way from the start node to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The way from the start node to [`Search::target`], both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.target.as_ref()?)
+    }
+}
+
+/// Breadth-first traversal of everything reachable from `start`, costs are hop counts.
+pub fn bfs<G: Neighbours>(graph: &G, start: G::Node) -> Search<G::Node, usize> {
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, hops)) = queue.pop_front() {
+        for (neighbour, _) in graph.neighbours(&node) {
+            if search.contains(&neighbour) {
+                continue;
+            }
+            search.costs.insert(neighbour.clone(), hops + 1);
+            search.predecessors.insert(neighbour.clone(), node.clone());
+            search.order.push(neighbour.clone());
+            queue.push_back((neighbour, hops + 1));
+        }
+    }
+
+    tracing::debug!(visited = search.len(), "bfs finished");
+    search
+}
+
+/// Depth-first traversal of everything reachable from `start`, costs are the hop counts
+/// along the way the node was first reached. Of the neighbours of a node, the last one is
+/// followed first.
+pub fn dfs<G: Neighbours>(graph: &G, start: G::Node) -> Search<G::Node, usize> {
+    let mut search = Search::new(start.clone(), 0);
+    let mut stack = graph
+        .neighbours(&start)
+        .map(|(neighbour, _)| (neighbour, start.clone(), 1))
+        .collect::<Vec<_>>();
+
+    // Nodes are only visited once popped, so they can still be reached deeper down the way
+    // they were pushed from
+    while let Some((node, from, hops)) = stack.pop() {
+        if search.contains(&node) {
+            continue;
+        }
+        search.costs.insert(node.clone(), hops);
+        search.predecessors.insert(node.clone(), from);
+        search.order.push(node.clone());
+
+        for (neighbour, _) in graph.neighbours(&node) {
+            if !search.contains(&neighbour) {
+                stack.push((neighbour, node.clone(), hops + 1));
+            }
+        }
+    }
+
+    tracing::debug!(visited = search.len(), "dfs finished");
+    search
+}
+
+/// Cheapest way from `start` to the first node satisfying `goal`. Stops as soon as that node
+/// is settled, so [`Search::costs`] only holds final costs for nodes cheaper than it.
+pub fn dijkstra<G, F>(graph: &G, start: G::Node, goal: F) -> Search<G::Node, G::Cost>
+where
+    G: Neighbours,
+    F: Fn(&G::Node) -> bool,
+{
+    astar(graph, start, goal, |_| G::Cost::default())
+}
+
+/// Same as [`dijkstra`], exploring nodes in the order of their cost plus `heuristic`. The
+/// heuristic must never overestimate the remaining cost for the result to be the cheapest.
+pub fn astar<G, F, H>(graph: &G, start: G::Node, goal: F, heuristic: H) -> Search<G::Node, G::Cost>
+where
+    G: Neighbours,
+    F: Fn(&G::Node) -> bool,
+    H: Fn(&G::Node) -> G::Cost,
+{
+    let zero = G::Cost::default();
+    let mut search = Search::new(start.clone(), zero);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: zero,
+        node: start,
+    }]);
+    // Pops of entries that got outdated by a cheaper way count as well
+    let (mut pops, mut pushes) = (0usize, 1usize);
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        pops += 1;
+        if search.costs.get(&node).is_some_and(|&known| known < cost) {
+            continue;
+        }
+        if goal(&node) {
+            search.target = Some(node);
+            break;
+        }
+
+        for (neighbour, step) in graph.neighbours(&node) {
+            let cost = cost + step;
+            if search
+                .costs
+                .get(&neighbour)
+                .is_some_and(|&known| known <= cost)
+            {
+                continue;
+            }
+
+            if search.costs.insert(neighbour.clone(), cost).is_none() {
+                search.order.push(neighbour.clone());
+            }
+            search.predecessors.insert(neighbour.clone(), node.clone());
+            heap.push(Entry {
+                priority: cost + heuristic(&neighbour),
+                cost,
+                node: neighbour,
+            });
+            pushes += 1;
+        }
+    }
+
+    tracing::debug!(
+        pops,
+        pushes,
+        reached = search.len(),
+        found = search.target.is_some(),
+        "search finished"
+    );
+    search
+}
+
+/// Where [`cheapest`] keeps the best known cost of each node, so that dense graphs like grids
+/// can use a flat table instead of a map.
+pub trait Costs<N, C> {
+    fn get(&self, node: &N) -> Option<C>;
+    fn set(&mut self, node: &N, cost: C);
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Costs<N, C> for HashMap<N, C> {
+    fn get(&self, node: &N) -> Option<C> {
+        HashMap::get(self, node).copied()
+    }
+
+    fn set(&mut self, node: &N, cost: C) {
+        self.insert(node.clone(), cost);
+    }
+}
+
+/// The first node satisfying `goal` along with the cost of the cheapest way there, like
+/// [`dijkstra`] but keeping nothing besides `costs`. Meant for searches too big to also record
+/// predecessors and the visiting order.
+pub fn cheapest<G, F, T>(
+    graph: &G,
+    start: G::Node,
+    goal: F,
+    costs: &mut T,
+) -> Option<(G::Node, G::Cost)>
+where
+    G: Neighbours,
+    F: Fn(&G::Node) -> bool,
+    T: Costs<G::Node, G::Cost>,
+{
+    let zero = G::Cost::default();
+    costs.set(&start, zero);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: zero,
+        cost: zero,
+        node: start,
+    }]);
+    let (mut pops, mut pushes) = (0usize, 1usize);
+
+    let mut found = None;
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        pops += 1;
+        if costs.get(&node).is_some_and(|known| known < cost) {
+            continue;
+        }
+        if goal(&node) {
+            found = Some((node, cost));
+            break;
+        }
+
+        for (neighbour, step) in graph.neighbours(&node) {
+            let cost = cost + step;
+            if costs.get(&neighbour).is_some_and(|known| known <= cost) {
+                continue;
+            }
+            costs.set(&neighbour, cost);
+            heap.push(Entry {
+                priority: cost,
+                cost,
+                node: neighbour,
+            });
+            pushes += 1;
+        }
+    }
+
+    tracing::debug!(pops, pushes, found = found.is_some(), "search finished");
+    found
+}
+
+/// Number of distinct walks from `start` to nodes satisfying `goal`, which end there. The part
+/// of the graph reachable from `start` has to be acyclic.
+pub fn count_paths<G, F>(graph: &G, start: G::Node, goal: F) -> usize
+where
+    G: Neighbours,
+    F: Fn(&G::Node) -> bool,
+{
+    fn count<G, F>(
+        graph: &G,
+        node: &G::Node,
+        goal: &F,
+        known: &mut HashMap<G::Node, usize>,
+    ) -> usize
+    where
+        G: Neighbours,
+        F: Fn(&G::Node) -> bool,
+    {
+        if goal(node) {
+            return 1;
+        }
+        if let Some(paths) = known.get(node) {
+            return *paths;
+        }
+
+        let paths = graph
+            .neighbours(node)
+            .map(|(neighbour, _)| count(graph, &neighbour, goal, known))
+            .sum();
+        known.insert(node.clone(), paths);
+        paths
+    }
+
+    let mut known = HashMap::new();
+    let paths = count(graph, &start, &goal, &mut known);
+    tracing::debug!(states = known.len(), paths, "paths counted");
+    paths
+}
+
+/// Heap entry ordered by priority alone, cheapest first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{astar, bfs, cheapest, count_paths, dfs, dijkstra, Neighbours};
+
+    /// a -1-> b -1-> d, a -5-> c -1-> d, d -1-> e
+    struct Weighted;
+
+    impl Neighbours for Weighted {
+        type Node = char;
+        type Cost = u32;
+
+        fn neighbours(&self, node: &char) -> impl Iterator<Item = (char, u32)> {
+            let edges: &[(char, u32)] = match node {
+                'a' => &[('c', 5), ('b', 1)],
+                'b' => &[('d', 1)],
+                'c' => &[('d', 1)],
+                'd' => &[('e', 1)],
+                _ => &[],
+            };
+            edges.iter().copied()
+        }
+    }
+
+    /// a -> b -> c and a -> c
+    struct Diamond;
+
+    impl Neighbours for Diamond {
+        type Node = char;
+        type Cost = u32;
+
+        fn neighbours(&self, node: &char) -> impl Iterator<Item = (char, u32)> {
+            let edges: &[(char, u32)] = match node {
+                'a' => &[('c', 1), ('b', 1)],
+                'b' => &[('c', 1)],
+                _ => &[],
+            };
+            edges.iter().copied()
+        }
+    }
+
+    /// Unbounded line of integers
+    struct Line;
+
+    impl Neighbours for Line {
+        type Node = i32;
+        type Cost = i32;
+
+        fn neighbours(&self, node: &i32) -> impl Iterator<Item = (i32, i32)> {
+            [(node - 1, 1), (node + 1, 1)].into_iter()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(&Weighted, 'a');
+        assert_eq!(search.len(), 5);
+        assert_eq!(search.visited(), &['a', 'c', 'b', 'd', 'e']);
+        assert_eq!(search.cost(&'e'), Some(3));
+        assert_eq!(search.path_to(&'e'), Some(vec!['a', 'c', 'd', 'e']));
+        assert_eq!(search.path_to(&'x'), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let search = dfs(&Weighted, 'a');
+        assert_eq!(search.visited(), &['a', 'b', 'd', 'e', 'c']);
+        assert_eq!(search.predecessor(&'d'), Some(&'b'));
+
+        let search = dfs(&Diamond, 'a');
+        assert_eq!(search.visited(), &['a', 'b', 'c']);
+        assert_eq!(search.predecessor(&'c'), Some(&'b'));
+        assert_eq!(search.cost(&'c'), Some(2));
+        assert_eq!(search.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(&Weighted, 'a', |node| *node == 'e');
+        assert_eq!(search.target(), Some(&'e'));
+        assert_eq!(search.cost(&'e'), Some(3));
+        assert_eq!(search.path(), Some(vec!['a', 'b', 'd', 'e']));
+
+        let search = dijkstra(&Weighted, 'a', |node| *node == 'x');
+        assert_eq!(search.target(), None);
+        assert_eq!(search.cost(&'c'), Some(5));
+    }
+
+    #[test]
+    fn test_cheapest() {
+        let mut costs = HashMap::new();
+        assert_eq!(
+            cheapest(&Weighted, 'a', |node| *node == 'e', &mut costs),
+            Some(('e', 3))
+        );
+        assert_eq!(costs.get(&'d'), Some(&2));
+        assert_eq!(
+            cheapest(&Weighted, 'a', |node| *node == 'x', &mut HashMap::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let search = astar(&Line, 0, |node| *node == 10, |node| (10 - node).abs());
+        assert_eq!(search.cost(&10), Some(10));
+        assert_eq!(search.path().map(|path| path.len()), Some(11));
+        // Without a heuristic the other direction gets explored as well
+        assert!(search.len() < dijkstra(&Line, 0, |node| *node == 10).len());
+    }
+
+    #[test]
+    fn test_count_paths() {
+        assert_eq!(count_paths(&Weighted, 'a', |node| *node == 'e'), 2);
+        assert_eq!(count_paths(&Weighted, 'c', |node| *node == 'e'), 1);
+        assert_eq!(count_paths(&Weighted, 'e', |node| *node == 'a'), 0);
+    }
+}